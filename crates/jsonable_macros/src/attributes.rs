@@ -0,0 +1,736 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    bracketed,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token, Attribute, Ident, Lit, Path, Token, Type,
+};
+
+/// A single entry inside `#[jsonable(...)]`
+///
+/// `Flag` - `#[jsonable(name)]`
+///
+/// `Value` - `#[jsonable(name = "literal")]`
+///
+/// `Values` - `#[jsonable(name = ["literal", ..])]`
+///
+/// `List` - `#[jsonable(name(nested, entries = 1))]`
+pub enum AttributeItem {
+    Flag(Ident),
+    Value(Ident, Lit),
+    Values(Ident, Vec<Lit>),
+    List(Ident, Vec<AttributeItem>),
+}
+
+impl Parse for AttributeItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            if input.peek(token::Bracket) {
+                let content;
+                bracketed!(content in input);
+                let values = Punctuated::<Lit, Token![,]>::parse_terminated(&content)?;
+                Ok(Self::Values(name, values.into_iter().collect()))
+            } else {
+                Ok(Self::Value(name, input.parse()?))
+            }
+        } else if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let items = Punctuated::<AttributeItem, Token![,]>::parse_terminated(&content)?;
+            Ok(Self::List(name, items.into_iter().collect()))
+        } else {
+            Ok(Self::Flag(name))
+        }
+    }
+}
+
+impl AttributeItem {
+    pub fn name(&self) -> String {
+        match self {
+            Self::Flag(name)
+            | Self::Value(name, _)
+            | Self::Values(name, _)
+            | Self::List(name, _) => name.to_string(),
+        }
+    }
+
+    pub fn string_value(&self) -> Result<String, String> {
+        match self {
+            Self::Value(_, Lit::Str(value)) => Ok(value.value()),
+            _ => Err(format!(
+                "Expected `{} = \"...\"` in jsonable attribute",
+                self.name()
+            )),
+        }
+    }
+
+    pub fn string_values(&self) -> Result<Vec<String>, String> {
+        let error = || {
+            format!(
+                "Expected `{} = [\"...\", ...]` in jsonable attribute",
+                self.name()
+            )
+        };
+
+        match self {
+            Self::Values(_, values) => values
+                .iter()
+                .map(|value| match value {
+                    Lit::Str(value) => Ok(value.value()),
+                    _ => Err(error()),
+                })
+                .collect(),
+            _ => Err(error()),
+        }
+    }
+
+    pub fn usize_value(&self) -> Result<usize, String> {
+        match self {
+            Self::Value(_, Lit::Int(value)) => value.base10_parse().map_err(|err| err.to_string()),
+            _ => Err(format!(
+                "Expected `{} = <integer>` in jsonable attribute",
+                self.name()
+            )),
+        }
+    }
+
+    pub fn expect_flag(&self) -> Result<(), String> {
+        match self {
+            Self::Flag(_) => Ok(()),
+            _ => Err(format!(
+                "Expected `{}` without a value in jsonable attribute",
+                self.name()
+            )),
+        }
+    }
+}
+
+/// Collects every entry of every `#[jsonable(...)]` attribute in `attrs`
+pub fn parse_attributes(attrs: &[Attribute]) -> Result<Vec<AttributeItem>, String> {
+    let mut items = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("jsonable")) {
+        let parsed = attr
+            .parse_args_with(Punctuated::<AttributeItem, Token![,]>::parse_terminated)
+            .map_err(|err| format!("Invalid jsonable attribute: {}", err))?;
+        items.extend(parsed);
+    }
+
+    Ok(items)
+}
+
+/// Options set on a struct or enum with `#[jsonable(...)]`
+#[derive(Default)]
+pub struct ContainerAttributes {
+    /// Key holding the variant name of an internally tagged enum.
+    ///
+    /// A struct variant may have a field with the same key, which is then read from the tag.
+    /// The variant name is always written to the key, whatever the field holds.
+    pub tag: Option<String>,
+    /// Key holding the variant's fields next to `tag`, for an adjacently tagged enum
+    pub content: Option<String>,
+    /// JSON pointer, such as `"/event/type"`, to the object holding an externally tagged enum
+    pub tag_path: Option<String>,
+    /// Two field tuple struct stored as a single key object, `{ <field 0>: <field 1> }`
+    pub as_entry: bool,
+    /// Single field tuple struct stored as its field alone
+    pub transparent: bool,
+    /// Unit variant of an enum stored as `null`
+    pub null_variant: Option<String>,
+    /// Enum stored as the bare content of its variant, read as the first variant that fits
+    pub untagged: bool,
+    /// Enum of unit variants stored as each variant's discriminant
+    pub numeric: bool,
+    /// Also emit a `<Name>Patch` struct with every field optional
+    pub generate_patch: bool,
+    /// Implement `into_json` by moving every field out of the consumed value, which types
+    /// implementing `Drop` cannot do
+    pub into_json: bool,
+    /// Named struct rejects object keys that match none of its fields
+    pub deny_unknown_fields: bool,
+    /// Case applied to the key of every field of a struct, or to the name of every variant of an
+    /// enum, unless the field or variant sets its own `rename`
+    pub rename_all: Option<RenameRule>,
+}
+
+impl ContainerAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        for item in parse_attributes(attrs)? {
+            match item.name().as_str() {
+                "tag" => result.tag = Some(item.string_value()?),
+                "content" => result.content = Some(item.string_value()?),
+                "tag_path" => result.tag_path = Some(item.string_value()?),
+                "as_entry" => {
+                    item.expect_flag()?;
+                    result.as_entry = true;
+                }
+                "transparent" => {
+                    item.expect_flag()?;
+                    result.transparent = true;
+                }
+                "deny_unknown_fields" => {
+                    item.expect_flag()?;
+                    result.deny_unknown_fields = true;
+                }
+                "generate_patch" => {
+                    item.expect_flag()?;
+                    result.generate_patch = true;
+                }
+                "into_json" => {
+                    item.expect_flag()?;
+                    result.into_json = true;
+                }
+                "null_variant" => {
+                    if result.null_variant.is_some() {
+                        return Err("Only one variant can be set as `null_variant`".into());
+                    }
+                    result.null_variant = Some(item.string_value()?);
+                }
+                "rename_all" => result.rename_all = Some(RenameRule::parse(&item)?),
+                "untagged" => {
+                    item.expect_flag()?;
+                    result.untagged = true;
+                }
+                "numeric" => {
+                    item.expect_flag()?;
+                    result.numeric = true;
+                }
+                other => return Err(format!("Unknown jsonable container attribute `{}`", other)),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Errors if any enum-only option is set on the struct `identifier`
+    pub fn expect_struct(&self, identifier: &Ident) -> Result<(), String> {
+        if self.tag.is_some() {
+            Err(format!("`tag` cannot be used on struct {}", identifier))
+        } else if self.content.is_some() {
+            Err(format!("`content` cannot be used on struct {}", identifier))
+        } else if self.tag_path.is_some() {
+            Err(format!(
+                "`tag_path` cannot be used on struct {}",
+                identifier
+            ))
+        } else if self.null_variant.is_some() {
+            Err(format!(
+                "`null_variant` cannot be used on struct {}",
+                identifier
+            ))
+        } else if self.untagged {
+            Err(format!(
+                "`untagged` cannot be used on struct {}",
+                identifier
+            ))
+        } else if self.numeric {
+            Err(format!("`numeric` cannot be used on struct {}", identifier))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Options set on a single enum variant with `#[jsonable(...)]`
+#[derive(Default)]
+pub struct VariantAttributes {
+    /// Extra names accepted when reading the variant. The variant's own name is always written.
+    pub aliases: Vec<String>,
+    /// Name of the variant in json, replacing the enum's `rename_all`
+    pub rename: Option<String>,
+    /// Case applied to the variant's field keys
+    pub rename_all: Option<RenameRule>,
+}
+
+impl VariantAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        for item in parse_attributes(attrs)? {
+            match item.name().as_str() {
+                "alias" => result.aliases.push(item.string_value()?),
+                "rename" => result.rename = Some(item.string_value()?),
+                "rename_all" => result.rename_all = Some(RenameRule::parse(&item)?),
+                other => return Err(format!("Unknown jsonable variant attribute `{}`", other)),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Name of the variant `ident` in json. An explicit `rename` wins over the enum's `rename_all`.
+    pub fn name(&self, ident: &Ident, rename_all: Option<RenameRule>) -> String {
+        let name = ident.unraw().to_string();
+        match (&self.rename, rename_all) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(rule)) => rule.apply(&name),
+            (None, None) => name,
+        }
+    }
+}
+
+/// `#[jsonable(rename_all = "camelCase")]`
+///
+/// Field names are split into words on underscores and lowercase to uppercase boundaries, then
+/// joined in the chosen case.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Camel,
+    Snake,
+    Pascal,
+    Kebab,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    fn parse(item: &AttributeItem) -> Result<Self, String> {
+        match item.string_value()?.as_str() {
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "PascalCase" => Ok(Self::Pascal),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            other => Err(format!("Unsupported rename_all case `{}`", other)),
+        }
+    }
+
+    pub fn apply(self, name: &str) -> String {
+        let mut words: Vec<String> = Vec::new();
+        let mut previous_lowercase = false;
+        for c in name.chars() {
+            if c == '_' {
+                words.push(String::new());
+                previous_lowercase = false;
+                continue;
+            }
+            if words.is_empty() || (c.is_uppercase() && previous_lowercase) {
+                words.push(String::new());
+            }
+            words.last_mut().unwrap().push(c);
+            previous_lowercase = c.is_lowercase() || c.is_numeric();
+        }
+        let words = words
+            .into_iter()
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase());
+
+        match self {
+            Self::Camel => words
+                .enumerate()
+                .map(|(index, word)| if index == 0 { word } else { capitalize(&word) })
+                .collect(),
+            Self::Snake => words.collect::<Vec<_>>().join("_"),
+            Self::Pascal => words.map(|word| capitalize(&word)).collect(),
+            Self::Kebab => words.collect::<Vec<_>>().join("-"),
+            Self::ScreamingSnake => words.collect::<Vec<_>>().join("_").to_uppercase(),
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Options set on a single field with `#[jsonable(...)]`
+#[derive(Default)]
+pub struct FieldAttributes {
+    /// Extra keys accepted when reading the field. The field's own key is always written.
+    pub aliases: Vec<String>,
+    pub as_hex: bool,
+    pub coerce_bool: bool,
+    pub dedup: bool,
+    /// Expression producing the field's value when its key is absent
+    pub default: Option<TokenStream>,
+    pub duration: Option<String>,
+    pub empty_as_none: bool,
+    pub flatten: bool,
+    pub prefix: Option<String>,
+    pub object_as_vec: bool,
+    pub rename: Option<String>,
+    /// Field never read from or written to json, always built from `default`
+    pub skip: bool,
+    pub required: bool,
+    pub lowercase_keys: bool,
+    pub null_as_empty: bool,
+    pub null_as_zero: bool,
+    pub items: Option<ItemsConstraint>,
+    pub max_bytes: Option<usize>,
+    pub required_keys: Option<Vec<String>>,
+    /// Field converted by its serde `Serialize` and `Deserialize` impls instead of `Jsonable`
+    pub with_serde: bool,
+    /// Module providing `from_json_unchecked`, `to_json`, and `validate_json` for the field
+    pub with: Option<Path>,
+}
+
+/// `#[jsonable(items(min = 1, max = 10, unique))]`
+#[derive(Default)]
+pub struct ItemsConstraint {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+    pub unique: bool,
+}
+
+impl ItemsConstraint {
+    fn parse(item: &AttributeItem) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        match item {
+            AttributeItem::List(_, entries) => {
+                for entry in entries {
+                    match entry.name().as_str() {
+                        "min" => result.min = Some(entry.usize_value()?),
+                        "max" => result.max = Some(entry.usize_value()?),
+                        "unique" => {
+                            entry.expect_flag()?;
+                            result.unique = true;
+                        }
+                        other => return Err(format!("Unknown items constraint `{}`", other)),
+                    }
+                }
+            }
+            _ => return Err("Expected `items(...)` in jsonable attribute".into()),
+        }
+
+        Ok(result)
+    }
+}
+
+impl FieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        for item in parse_attributes(attrs)? {
+            match item.name().as_str() {
+                "alias" => result.aliases.push(item.string_value()?),
+                "as_hex" => {
+                    item.expect_flag()?;
+                    result.as_hex = true;
+                }
+                "coerce_bool" => {
+                    item.expect_flag()?;
+                    result.coerce_bool = true;
+                }
+                "dedup" => {
+                    item.expect_flag()?;
+                    result.dedup = true;
+                }
+                "default" => {
+                    result.default = Some(match &item {
+                        AttributeItem::Flag(_) => quote! { Default::default() },
+                        _ => {
+                            let path: Path = syn::parse_str(&item.string_value()?)
+                                .map_err(|err| format!("Invalid `default` path: {}", err))?;
+                            quote! { #path() }
+                        }
+                    })
+                }
+                "duration" => match item.string_value()?.as_str() {
+                    "iso8601" => result.duration = Some("iso8601".into()),
+                    other => return Err(format!("Unsupported duration format `{}`", other)),
+                },
+                // Floats read and write non-finite strings by default, the flag is kept so
+                // existing fields still compile
+                "allow_non_finite" => item.expect_flag()?,
+                "empty_as_none" => {
+                    item.expect_flag()?;
+                    result.empty_as_none = true;
+                }
+                "flatten" => {
+                    item.expect_flag()?;
+                    result.flatten = true;
+                }
+                "items" => result.items = Some(ItemsConstraint::parse(&item)?),
+                "lowercase_keys" => {
+                    item.expect_flag()?;
+                    result.lowercase_keys = true;
+                }
+                "max_bytes" => result.max_bytes = Some(item.usize_value()?),
+                "null_as_empty" => {
+                    item.expect_flag()?;
+                    result.null_as_empty = true;
+                }
+                "null_as_zero" => {
+                    item.expect_flag()?;
+                    result.null_as_zero = true;
+                }
+                "object_as_vec" => {
+                    item.expect_flag()?;
+                    result.object_as_vec = true;
+                }
+                "prefix" => result.prefix = Some(item.string_value()?),
+                "rename" => result.rename = Some(item.string_value()?),
+                "skip" => {
+                    item.expect_flag()?;
+                    result.skip = true;
+                }
+                "required" => {
+                    item.expect_flag()?;
+                    result.required = true;
+                }
+                "required_keys" => result.required_keys = Some(item.string_values()?),
+                "with" => {
+                    result.with = Some(
+                        syn::parse_str(&item.string_value()?)
+                            .map_err(|err| format!("Invalid `with` path: {}", err))?,
+                    )
+                }
+                "with_serde" => {
+                    item.expect_flag()?;
+                    result.with_serde = true;
+                }
+                other => return Err(format!("Unknown jsonable field attribute `{}`", other)),
+            }
+        }
+
+        if result.prefix.is_some() && !result.flatten {
+            return Err("`prefix` can only be used together with `flatten`".into());
+        }
+        if result.default.is_some() && result.flatten {
+            return Err("`default` cannot be used together with `flatten`".into());
+        }
+        if result.skip && result.flatten {
+            return Err("`skip` cannot be used together with `flatten`".into());
+        }
+        if !result.aliases.is_empty() && (result.flatten || result.skip) {
+            return Err("`alias` cannot be used together with `flatten` or `skip`".into());
+        }
+        let codecs = result.codecs();
+        if codecs.len() > 1 {
+            return Err(format!(
+                "`{}` cannot be used together, a field is converted by a single codec",
+                codecs.join("`, `")
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// JSON key of the field named `ident`. An explicit `rename` wins over the `rename_all` rule.
+    pub fn key(&self, ident: &Ident, rename_all: Option<RenameRule>) -> String {
+        let name = ident.unraw().to_string();
+        match (&self.rename, rename_all) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(rule)) => rule.apply(&name),
+            (None, None) => name,
+        }
+    }
+
+    /// Expression borrowing the field's value out of the object `map` as an `Option`, looking up
+    /// `key` first and then each alias in order.
+    pub fn get(&self, map: TokenStream, key: &str) -> TokenStream {
+        let aliases = &self.aliases;
+        quote! { #map.get(#key)#(.or_else(|| #map.get(#aliases)))* }
+    }
+
+    /// Expression moving the field's value out of the object `map` as an `Option`, preferring
+    /// `key` over the aliases. Every alias is removed, so none is left behind as an unknown key.
+    pub fn remove(&self, map: TokenStream, key: &str) -> TokenStream {
+        let aliases = &self.aliases;
+        if aliases.is_empty() {
+            return quote! { #map.remove(#key) };
+        }
+
+        quote! {{
+            let mut value = #map.remove(#key);
+            #(
+                let alias = #map.remove(#aliases);
+                if value.is_none() {
+                    value = alias;
+                }
+            )*
+            value
+        }}
+    }
+
+    /// Errors if `flatten` is set on a field that is not part of a named struct, `owner`.
+    pub fn expect_unflattened(&self, owner: &str) -> Result<(), String> {
+        if self.flatten {
+            Err(format!("`flatten` cannot be used on fields of {}", owner))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Errors if an option only supported by named struct fields is set on a field of `owner`.
+    pub fn expect_plain(&self, owner: &str) -> Result<(), String> {
+        self.expect_unflattened(owner)?;
+        if self.default.is_some() {
+            Err(format!("`default` cannot be used on fields of {}", owner))
+        } else if self.skip {
+            Err(format!("`skip` cannot be used on fields of {}", owner))
+        } else if !self.aliases.is_empty() {
+            Err(format!("`alias` cannot be used on fields of {}", owner))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Path providing `from_json_unchecked`, `to_json`, and `validate_json` for the field.
+    ///
+    /// Defaults to the field type's own `Jsonable` impl.
+    pub fn codec(&self, ty: &Type) -> TokenStream {
+        if let Some(with) = &self.with {
+            return quote! { #with };
+        }
+        match self.duration.as_deref() {
+            Some("iso8601") => quote! { jsonable::iso8601::duration },
+            _ if self.as_hex => quote! { jsonable::hex::Hex::<#ty> },
+            _ if self.coerce_bool => quote! { jsonable::coerce_bool::CoerceBool::<#ty> },
+            _ if self.dedup => quote! { jsonable::dedup::Dedup::<#ty> },
+            _ if self.empty_as_none => quote! { jsonable::empty_as_none::EmptyAsNone::<#ty> },
+            _ if self.object_as_vec => quote! { jsonable::object_as_vec::ObjectAsVec::<#ty> },
+            _ if self.lowercase_keys => quote! { jsonable::lowercase_keys::LowercaseKeys::<#ty> },
+            _ if self.null_as_empty => quote! { jsonable::null_as_empty::NullAsEmpty::<#ty> },
+            _ if self.null_as_zero => quote! { jsonable::null_as_zero::NullAsZero::<#ty> },
+            _ if self.required => quote! { jsonable::required::Required::<#ty> },
+            _ if self.with_serde => quote! { jsonable::with_serde::WithSerde::<#ty> },
+            _ => quote! { <#ty as jsonable::Jsonable> },
+        }
+    }
+
+    /// Expression validating the `&serde_json::Value` produced by `value` against the field's
+    /// codec followed by any constraints set on the field.
+    pub fn validate(&self, ty: &Type, value: TokenStream) -> TokenStream {
+        let codec = self.codec(ty);
+        let constraints = self.constraints();
+
+        quote! {{
+            let value: &serde_json::Value = #value;
+            #codec::validate_json(value)#(.and_then(|_| #constraints))*
+        }}
+    }
+
+    /// Expression converting the owned `serde_json::Value` produced by `value` into a
+    /// `jsonable::Result` of the field type, validating it along the way.
+    ///
+    /// Fields using the type's own `Jsonable` impl without constraints are converted in a
+    /// single pass with `from_json_fused`.
+    pub fn fused(&self, ty: &Type, value: TokenStream) -> TokenStream {
+        let codec = self.codec(ty);
+        let constraints = self.constraints();
+
+        if !self.has_codec() && constraints.is_empty() {
+            return quote! { #codec::from_json_fused(#value) };
+        }
+
+        let validate = self.validate(ty, quote! { &value });
+        quote! {{
+            let value: serde_json::Value = #value;
+            #validate.map(|_| #codec::from_json_unchecked(value))
+        }}
+    }
+
+    /// Expression converting the owned field produced by `value` into a `serde_json::Value`.
+    ///
+    /// Fields using the type's own `Jsonable` impl are moved with `into_json`, others are
+    /// written by their codec.
+    pub fn owned_json(&self, ty: &Type, value: TokenStream) -> TokenStream {
+        let codec = self.codec(ty);
+
+        if self.has_codec() {
+            quote! { #codec::to_json(&#value) }
+        } else {
+            quote! { #codec::into_json(#value) }
+        }
+    }
+
+    /// Expression producing the JSON Schema of the field's json.
+    ///
+    /// A `with` module is not required to describe its json, so its fields accept anything.
+    pub fn json_schema(&self, ty: &Type) -> TokenStream {
+        match self.with {
+            Some(_) => quote! { serde_json::json!({}) },
+            None => {
+                let codec = self.codec(ty);
+                quote! { #codec::json_schema() }
+            }
+        }
+    }
+
+    /// `jsonable::schema::ObjectSchema` method call describing the field stored under `key`.
+    ///
+    /// A field is required unless it has a `default` or its codec accepts `null`, since a missing
+    /// key is read as `null`.
+    pub fn schema(&self, ty: &Type, key: &str) -> TokenStream {
+        let codec = self.codec(ty);
+        let schema = self.json_schema(ty);
+
+        if self.flatten {
+            let prefix = self.prefix.clone().unwrap_or_default();
+            return quote! { .flatten(#prefix, jsonable::schema::inline(|| #schema)) };
+        }
+
+        let required = match self.default {
+            Some(_) => quote! { false },
+            None => quote! { #codec::validate_json(&serde_json::Value::Null).is_err() },
+        };
+        quote! { .property(#key, #schema, #required) }
+    }
+
+    /// Whether the field is converted by a codec rather than its type's own `Jsonable` impl
+    fn has_codec(&self) -> bool {
+        !self.codecs().is_empty()
+    }
+
+    /// Names of the attributes selecting a codec for the field, at most one is allowed
+    fn codecs(&self) -> Vec<&'static str> {
+        [
+            ("as_hex", self.as_hex),
+            ("coerce_bool", self.coerce_bool),
+            ("dedup", self.dedup),
+            ("duration", self.duration.is_some()),
+            ("empty_as_none", self.empty_as_none),
+            ("lowercase_keys", self.lowercase_keys),
+            ("null_as_empty", self.null_as_empty),
+            ("null_as_zero", self.null_as_zero),
+            ("object_as_vec", self.object_as_vec),
+            ("required", self.required),
+            ("with", self.with.is_some()),
+            ("with_serde", self.with_serde),
+        ]
+        .into_iter()
+        .filter(|(_, set)| *set)
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    fn constraints(&self) -> Vec<TokenStream> {
+        let mut constraints = Vec::new();
+
+        if let Some(items) = &self.items {
+            let min = optional(items.min);
+            let max = optional(items.max);
+            let unique = items.unique;
+            constraints.push(quote! { jsonable::constraints::items(value, #min, #max, #unique) });
+        }
+
+        if let Some(max) = self.max_bytes {
+            constraints.push(quote! { jsonable::constraints::max_bytes(value, #max) });
+        }
+
+        if let Some(keys) = &self.required_keys {
+            constraints.push(quote! { jsonable::constraints::required_keys(value, &[#(#keys),*]) });
+        }
+
+        constraints
+    }
+}
+
+fn optional(value: Option<usize>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
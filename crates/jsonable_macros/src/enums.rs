@@ -1,11 +1,100 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{punctuated::Punctuated, token::Comma, Fields, FieldsNamed, FieldsUnnamed, Variant};
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, token::Comma, Fields, FieldsNamed, FieldsUnnamed,
+    Generics, Variant,
+};
+
+use crate::{
+    attributes::{ContainerAttributes, FieldAttributes, RenameRule, VariantAttributes},
+    implement_schema_ref_name, with_jsonable_bounds,
+};
 
 pub fn implement(
     identifier: &Ident,
+    generics: &Generics,
+    attributes: &ContainerAttributes,
     variants: Punctuated<Variant, Comma>,
 ) -> Result<TokenStream, String> {
+    if attributes.as_entry || attributes.transparent {
+        return Err(format!(
+            "`as_entry` and `transparent` cannot be used on enum {}",
+            identifier
+        ));
+    }
+    if attributes.generate_patch || attributes.into_json {
+        return Err(format!(
+            "`generate_patch` and `into_json` cannot be used on enum {}",
+            identifier
+        ));
+    }
+    if attributes.deny_unknown_fields && (attributes.untagged || attributes.numeric) {
+        return Err(format!(
+            "`deny_unknown_fields` cannot be combined with `untagged` or `numeric` on enum {}",
+            identifier
+        ));
+    }
+    let null_variant = attributes.null_variant.as_ref();
+    check_null_variant(identifier, null_variant, &variants)?;
+    let variant_tag =
+        implement_variant_tag(identifier, generics, attributes.rename_all, &variants)?;
+    if attributes.numeric {
+        if attributes.untagged
+            || attributes.tag.is_some()
+            || attributes.content.is_some()
+            || attributes.tag_path.is_some()
+            || null_variant.is_some()
+        {
+            return Err(format!(
+                "`numeric` cannot be combined with `untagged`, `tag`, `content`, `tag_path`, or `null_variant` on enum {}",
+                identifier
+            ));
+        }
+        return implement_numeric(identifier, generics, variants)
+            .map(|output| quote! { #variant_tag #output });
+    }
+    if attributes.untagged {
+        if attributes.tag.is_some()
+            || attributes.content.is_some()
+            || attributes.tag_path.is_some()
+            || null_variant.is_some()
+        {
+            return Err(format!(
+                "`untagged` cannot be combined with `tag`, `content`, `tag_path`, or `null_variant` on enum {}",
+                identifier
+            ));
+        }
+        return implement_untagged(identifier, generics, variants)
+            .map(|output| quote! { #variant_tag #output });
+    }
+    if attributes.tag.is_some() && attributes.tag_path.is_some() {
+        return Err(format!(
+            "`tag` and `tag_path` cannot both be used on enum {}",
+            identifier
+        ));
+    }
+    let (unwrap_path, validate_path, wrap_path) = match (&attributes.tag, &attributes.content) {
+        (Some(tag), Some(content)) => {
+            implement_adjacent_tag(identifier, tag, content, null_variant)
+        }
+        (None, Some(_)) => {
+            return Err(format!("`content` requires a `tag` on enum {}", identifier))
+        }
+        _ => implement_tag_path(identifier, attributes.tag_path.as_deref())?,
+    };
+    if let (Some(tag), None) = (&attributes.tag, &attributes.content) {
+        return implement_internally_tagged(
+            identifier,
+            generics,
+            tag,
+            null_variant,
+            attributes.rename_all,
+            attributes.deny_unknown_fields,
+            variants,
+        )
+        .map(|output| quote! { #variant_tag #output });
+    }
+
     let identifier_string = identifier.to_string();
     let mut from_json_unchecked_string: Vec<TokenStream> = Vec::new();
     let mut from_json_unchecked_object: Vec<TokenStream> = Vec::new();
@@ -13,55 +102,143 @@ pub fn implement(
     let mut validate_json_string: Vec<TokenStream> = Vec::new();
     let mut validate_json_object: Vec<TokenStream> = Vec::new();
     let mut expected_string_types: Vec<String> = Vec::new();
+    let mut object_keys: Vec<String> = Vec::new();
+    let mut expected_object_keys: Vec<String> = Vec::new();
+    let mut from_json_unchecked_null: Option<TokenStream> = None;
+    let mut schema_variants: Vec<TokenStream> = Vec::new();
 
     for variant in variants.into_iter() {
         let ident = variant.ident;
-        let ident_str = ident.to_string();
+        let variant_attributes = VariantAttributes::parse(&variant.attrs)?;
+        let ident_str = variant_attributes.name(&ident, attributes.rename_all);
+        let aliases = variant_attributes.aliases;
+        let rename_all = variant_attributes.rename_all;
         let fields = variant.fields;
+        if null_variant.is_none_or(|name| ident.unraw() != name) {
+            expected_object_keys.push(ident_str.clone());
+            object_keys.push(ident_str.clone());
+            object_keys.extend(aliases.iter().cloned());
+            let payload = match implement_payload_schema(
+                &fields,
+                rename_all,
+                attributes.deny_unknown_fields,
+            )? {
+                Some(payload) => quote! { Some(#payload) },
+                None => quote! { None },
+            };
+            schema_variants.push(quote! { (#ident_str, #payload) });
+        }
+
+        // Without `content` the adjacent form reads as the variant's bare name, which is only
+        // complete for unit variants
+        if let (Some(content), Fields::Named(_) | Fields::Unnamed(_)) =
+            (&attributes.content, &fields)
+        {
+            validate_json_string.push(quote! {
+                #ident_str #(| #aliases)* => Err(jsonable::JsonableError::MissingKeyForEnumVariant { variant: #ident_str, key: #content })
+            });
+        }
 
         match fields {
             Fields::Named(named_fields) => {
-                let (mut validate, mut to, mut from_unchecked) =
-                    match implement_named(&identifier_string, &ident, &ident_str, named_fields) {
-                        Ok(result) => result,
-                        Err(reason) => return Err(reason),
-                    };
+                let (mut validate, mut to, mut from_unchecked) = match implement_named(
+                    &identifier_string,
+                    &ident,
+                    &ident_str,
+                    &aliases,
+                    rename_all,
+                    attributes.deny_unknown_fields,
+                    named_fields,
+                ) {
+                    Ok(result) => result,
+                    Err(reason) => return Err(reason),
+                };
                 validate_json_object.append(&mut validate);
                 to_json.append(&mut to);
                 from_json_unchecked_object.append(&mut from_unchecked);
             }
             Fields::Unnamed(unnamed_fields) => {
-                let (mut validate, mut to, mut from_unchecked) =
-                    match implement_unnamed(&identifier_string, &ident, &ident_str, unnamed_fields)
-                    {
-                        Ok(result) => result,
-                        Err(reason) => return Err(reason),
-                    };
+                let (mut validate, mut to, mut from_unchecked) = match implement_unnamed(
+                    &identifier_string,
+                    &ident,
+                    &ident_str,
+                    &aliases,
+                    unnamed_fields,
+                ) {
+                    Ok(result) => result,
+                    Err(reason) => return Err(reason),
+                };
                 validate_json_object.append(&mut validate);
                 to_json.append(&mut to);
                 from_json_unchecked_object.append(&mut from_unchecked);
             }
+            Fields::Unit if null_variant.is_some_and(|name| ident.unraw() == name) => {
+                from_json_unchecked_null =
+                    Some(quote! { serde_json::Value::Null => Self::#ident, });
+                to_json.push(quote! { Self::#ident => serde_json::Value::Null });
+            }
+            // Written as a bare string, but also read from `{name: {}}` or `{name: null}`
             Fields::Unit => {
-                validate_json_string.push(quote! {#ident_str => Ok(())});
-                from_json_unchecked_string.push(quote! {#ident_str => Self::#ident});
+                validate_json_string.push(quote! {#ident_str #(| #aliases)* => Ok(())});
+                from_json_unchecked_string.push(quote! {#ident_str #(| #aliases)* => Self::#ident});
                 expected_string_types.push(ident_str.clone());
                 to_json
                     .push(quote! { Self::#ident => serde_json::Value::String(#ident_str.into())});
+                from_json_unchecked_object.push(quote! {#ident_str #(| #aliases)* => Self::#ident});
+                validate_json_object.push(implement_unit_payload(
+                    &identifier_string,
+                    &ident_str,
+                    &aliases,
+                ));
             }
         }
     }
+    let has_null_variant = null_variant.is_some();
+    let json_schema = match (&attributes.tag, &attributes.content, &attributes.tag_path) {
+        (Some(tag), Some(content), _) => quote! {
+            jsonable::schema::adjacently_tagged(#tag, #content, vec![#(#schema_variants,)*], #has_null_variant)
+        },
+        (_, _, Some(path)) => quote! {
+            jsonable::schema::at_path(#path, jsonable::schema::externally_tagged(vec![#(#schema_variants,)*], #has_null_variant))
+        },
+        _ => quote! {
+            jsonable::schema::externally_tagged(vec![#(#schema_variants,)*], #has_null_variant)
+        },
+    };
+    let (validate_json_null, expected_json_type) = match null_variant {
+        Some(_) => (quote! { Ok(()) }, "object, string, or null"),
+        None => (
+            quote! { Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "object or string" }) },
+            "object or string",
+        ),
+    };
+
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
     Ok(quote! {
-        impl jsonable::Jsonable for #identifier {
+        #variant_tag
+
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
             fn from_json_unchecked(mut json: serde_json::Value) -> Self {
+                #unwrap_path
                 match json {
+                    #from_json_unchecked_null
                     serde_json::Value::String(value) => {
                         match value.as_str() {
                             #(#from_json_unchecked_string,)*
-                            other => panic!("Unknown variant of enum '{}': {}", #identifier_string, value)
+                            other => panic!("Unknown variant of enum '{}': {}", #identifier_string, other)
                         }
                     },
                     serde_json::Value::Object(mut map) => {
-                        match map.keys().last().unwrap().as_str() {
+                        // Dispatch on the key naming a variant, whatever else the object holds
+                        let key = match map.keys().find(|key| [#(#object_keys),*].contains(&key.as_str())) {
+                            Some(key) => key.clone(),
+                            None => panic!("Unknown variant of enum '{}': {:?}", #identifier_string, map.keys().collect::<Vec<_>>())
+                        };
+                        match key.as_str() {
                             #(#from_json_unchecked_object,)*
                             other => panic!("Unknown variant of enum '{}': {}", #identifier_string, other)
                         }
@@ -71,49 +248,135 @@ pub fn implement(
             }
 
             fn to_json(&self) -> serde_json::Value {
-                match self {
+                let json = match self {
                     #(#to_json,)*
-                }
+                };
+                #wrap_path
+                json
+            }
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    #json_schema
+                })
             }
 
             fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
+                // The variant named `key` holding `payload`, as in `{key: payload}`
+                let validate_object = |key: &str, payload: &serde_json::Value| -> jsonable::Result<()> {
+                    let mut has_key = false;
+                    #(#validate_json_object)*
+
+                    if !has_key {
+                        Err(jsonable::JsonableError::IncorrectKeyForEnum { ty: #identifier_string, key: key.into(), expected: vec![#(#expected_object_keys,)*] })
+                    } else {
+                        Ok(())
+                    }
+                };
+                let validate_string = |value: &str| -> jsonable::Result<()> {
+                    match value {
+                        #(#validate_json_string,)*
+                        other => Err(jsonable::JsonableError::InvalidEnumStringVariant { enum_type: #identifier_string, got: other.into(), expected: vec![#(#expected_string_types,)*]})
+                    }
+                };
+
+                #validate_path
                 match json {
                     serde_json::Value::Object(map) => {
                         if map.len() == 1 {
-                            let mut has_key = false;
-                            #(#validate_json_object)*
-
-                            if !has_key {
-                                Err(jsonable::JsonableError::IncorrectKeyForEnum { ty: #identifier_string, key: map.keys().last().unwrap().clone() })
-                            } else {
-                                Ok(())
-                            }
+                            let (key, payload) = map.iter().next().unwrap();
+                            validate_object(key.as_str(), payload)
                         } else {
                             Err(jsonable::JsonableError::IncorrectObjectKeyCountForEnum {ty: #identifier_string, count: map.len() })
                         }
                     },
-                    serde_json::Value::String(value) => {
-                        match value.as_str() {
-                            #(#validate_json_string,)*
-                            other => Err(jsonable::JsonableError::InvalidEnumStringVariant { enum_type: #identifier_string, got: value.clone(), expected: vec![#(#expected_string_types,)*]})
-                        }
-                    },
-                    serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: "object or string" }),
-                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "object or string" }),
-                    serde_json::Value::Null => Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "object or string" }),
-                    serde_json::Value::Number(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: "object or string" }),
+                    serde_json::Value::String(value) => validate_string(value.as_str()),
+                    serde_json::Value::Null => #validate_json_null,
+                    serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: #expected_json_type }),
+                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: #expected_json_type }),
+                    serde_json::Value::Number(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: #expected_json_type }),
                 }
             }
         }
     })
 }
 
+/// Schema of what the externally tagged form stores under a variant's name, `None` for unit variants
+fn implement_payload_schema(
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    deny_unknown_fields: bool,
+) -> Result<Option<TokenStream>, String> {
+    Ok(match fields {
+        Fields::Named(fields) => {
+            let mut properties = Vec::new();
+            for field in &fields.named {
+                let attributes = FieldAttributes::parse(&field.attrs)?;
+                let key = attributes.key(field.ident.as_ref().unwrap(), rename_all);
+                properties.push(attributes.schema(&field.ty, &key));
+            }
+            Some(quote! {
+                jsonable::schema::ObjectSchema::new() #(#properties)* .build(#deny_unknown_fields)
+            })
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let field = fields.unnamed.first().unwrap();
+            Some(FieldAttributes::parse(&field.attrs)?.json_schema(&field.ty))
+        }
+        Fields::Unnamed(fields) => {
+            let mut schemas = Vec::new();
+            for field in &fields.unnamed {
+                schemas.push(FieldAttributes::parse(&field.attrs)?.json_schema(&field.ty));
+            }
+            Some(quote! {
+                jsonable::schema::tuple(vec![#(#schemas,)*])
+            })
+        }
+        Fields::Unit => None,
+    })
+}
+
+/// Validates the object form of a unit variant, whose payload must be `{}` or `null`
+fn implement_unit_payload(
+    type_ident_str: &str,
+    ident_str: &str,
+    aliases: &[String],
+) -> TokenStream {
+    let lookup = quote! { [#ident_str #(, #aliases)*].contains(&key).then_some(payload) };
+    quote! {
+        if let Some(variant_json) = #lookup.filter(|_| !has_key) {
+            has_key = true;
+
+            let got = match variant_json {
+                serde_json::Value::Null => return Ok(()),
+                serde_json::Value::Object(payload) => match payload.keys().next() {
+                    Some(key) => return Err(jsonable::JsonableError::UnknownField { ty: #type_ident_str, field: key.clone() }),
+                    None => return Ok(()),
+                },
+                serde_json::Value::Array(_) => "array",
+                serde_json::Value::Bool(_) => "bool",
+                serde_json::Value::Number(_) => "number",
+                serde_json::Value::String(_) => "string",
+            };
+            return Err(jsonable::JsonableError::VariantValidation {
+                variant: #ident_str,
+                error: Box::from(jsonable::JsonableError::IncompatibleJsonType { got, expected: "object or null" }),
+            })
+        }
+    }
+}
+
 fn implement_named(
     type_ident_str: &String,
     ident: &Ident,
     ident_str: &String,
+    aliases: &[String],
+    rename_all: Option<RenameRule>,
+    deny_unknown_fields: bool,
     fields: FieldsNamed,
 ) -> Result<(Vec<TokenStream>, Vec<TokenStream>, Vec<TokenStream>), String> {
+    let pattern = quote! { #ident_str #(| #aliases)* };
+    let lookup = quote! { [#ident_str #(, #aliases)*].contains(&key).then_some(payload) };
     let mut validate = Vec::new();
     let mut to_json = Vec::new();
     let mut from_unchecked = Vec::new();
@@ -125,36 +388,55 @@ fn implement_named(
     let mut from_unchecked_parts = Vec::new();
 
     let mut field_idents: Vec<Ident> = Vec::with_capacity(field_count);
+    let mut keys: Vec<String> = Vec::with_capacity(field_count);
 
     for field in named {
         let ty = field.ty;
         let field_ident = field.ident.unwrap();
-        let field_ident_str = field_ident.to_string();
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        attributes.expect_plain(&format!("enum {}", type_ident_str))?;
+        let field_ident_str = attributes.key(&field_ident, rename_all);
+        let codec = attributes.codec(&ty);
+        let validate = attributes.validate(&ty, quote! { value });
 
         from_unchecked_parts.push(quote!{
-            #field_ident: if let Some(value) = inner_map.remove(#field_ident_str) { <#ty as jsonable::Jsonable>::from_json_unchecked(value) } else { panic!("Missing field '{}' for variant `{}::{}`", #field_ident_str, #type_ident_str, #ident_str) }
+            #field_ident: #codec::from_json_unchecked(inner_map.remove(#field_ident_str).unwrap_or(serde_json::Value::Null))
         });
 
-        to_json_parts
-            .push(quote! {inner_map.insert(#field_ident_str.into(), #field_ident.to_json());});
+        to_json_parts.push(
+            quote! {inner_map.insert(#field_ident_str.into(), #codec::to_json(#field_ident));},
+        );
 
+        // A missing key reads as null, so `Option` fields may be left out. Fields rejecting null
+        // report the key as missing
         validate_parts.push(quote!{
-            if let Some(value) = inner_map.get(#field_ident_str) {
-                match <#ty as jsonable::Jsonable>::validate_json(value) {
-                    Ok(_) => {},
-                    Err(err) => errors.push(jsonable::JsonableError::InnerErrorForType {ty: std::any::type_name::<#ty>(), error: err.into()})
-                }
-            } else {
-                errors.push(jsonable::JsonableError::MissingKeyForEnumVariant {variant: #ident_str, key: #field_ident_str});
+            match inner_map.get(#field_ident_str) {
+                Some(value) => if let Err(err) = #validate {
+                    errors.push(jsonable::JsonableError::InvalidMapValue {
+                        key: #field_ident_str.into(),
+                        error: Box::from(jsonable::JsonableError::InnerErrorForType {ty: std::any::type_name::<#ty>(), error: err.into()}),
+                    })
+                },
+                None => if #codec::validate_json(&serde_json::Value::Null).is_err() {
+                    errors.push(jsonable::JsonableError::MissingKeyForEnumVariant {variant: #ident_str, key: #field_ident_str})
+                },
             }
         });
 
         field_idents.push(field_ident);
+        keys.push(field_ident_str);
     }
 
+    let validate_unknown_fields = implement_deny_unknown_fields(
+        type_ident_str,
+        deny_unknown_fields,
+        quote! { inner_map },
+        &keys,
+    );
+
     from_unchecked.push(quote!{
-        #ident_str => {
-            if let Some(inner_map) = map.remove(#ident_str).unwrap().as_object_mut() {
+        #pattern => {
+            if let Some(inner_map) = map.remove(&key).unwrap().as_object_mut() {
                 Self::#ident{#(#from_unchecked_parts,)*}
             } else {
                 panic!("Attempted converting non-object to enum variant `{}::{}`", #type_ident_str, #ident_str)
@@ -172,24 +454,18 @@ fn implement_named(
         }
     });
 
+    let variant_errors = implement_variant_errors(type_ident_str, ident_str);
     validate.push(quote!{
-        if !has_key && map.contains_key(#ident_str) {
+        if let Some(variant_json) = #lookup.filter(|_| !has_key) {
             has_key = true;
 
-            if let Some(inner_map) = map.get(#ident_str).unwrap().as_object() {
-                if inner_map.len() == #field_count {
-                    let mut errors = Vec::new();
+            if let Some(inner_map) = variant_json.as_object() {
+                let mut errors = Vec::new();
 
-                    #(#validate_parts)*
+                #(#validate_parts)*
+                #validate_unknown_fields
 
-                    if errors.len() > 0 {
-                        return Err(jsonable::JsonableError::InnerErrorsForType {ty: #type_ident_str, errors })
-                    } else {
-                        return Ok(())
-                    }
-                } else {
-                    return Err(jsonable::JsonableError::IncorrectFieldCountForEnum {enum_type: #type_ident_str, variant: #ident_str, count: #field_count})
-                }
+                return #variant_errors
             } else {
                 return Err(jsonable::JsonableError::IncompatibleJsonType {got: "other", expected: "object"})
             }
@@ -203,8 +479,11 @@ fn implement_unnamed(
     type_ident_str: &String,
     ident: &Ident,
     ident_str: &String,
+    aliases: &[String],
     fields: FieldsUnnamed,
 ) -> Result<(Vec<TokenStream>, Vec<TokenStream>, Vec<TokenStream>), String> {
+    let pattern = quote! { #ident_str #(| #aliases)* };
+    let lookup = quote! { [#ident_str #(, #aliases)*].contains(&key).then_some(payload) };
     let mut validate: Vec<TokenStream> = Vec::new();
     let mut to_json: Vec<TokenStream> = Vec::new();
     let mut from_unchecked: Vec<TokenStream> = Vec::new();
@@ -217,12 +496,17 @@ fn implement_unnamed(
         let mut from_unchecked_parts: Vec<TokenStream> = Vec::with_capacity(count);
         for (idx, field) in unnamed.iter().enumerate() {
             let ty = field.ty.clone();
+            let attributes = FieldAttributes::parse(&field.attrs)?;
+            attributes.expect_plain(&format!("enum {}", type_ident_str))?;
+            let codec = attributes.codec(&ty);
+            let validate = attributes.validate(&ty, quote! { &array[#idx] });
+
             from_unchecked_parts.push(quote! {
-                <#ty as jsonable::Jsonable>::from_json_unchecked(array.pop().unwrap())
+                #codec::from_json_unchecked(entries.next().unwrap())
             });
 
             validate_parts.push(quote!{
-                match <#ty as jsonable::Jsonable>::validate_json(array.get(#idx).unwrap()) {
+                match #validate {
                     Ok(_) => {},
                     Err(err) => errors.push(jsonable::JsonableError::InnerErrorForType {ty: std::any::type_name::<#ty>(), error: err.into()})
                 };
@@ -231,14 +515,15 @@ fn implement_unnamed(
             let field_name = Ident::new(format!("field{}", idx).as_str(), ident.span());
 
             to_json_parts.push(quote! {
-                array.push(#field_name.to_json())
+                array.push(#codec::to_json(#field_name))
             });
         }
 
         from_unchecked.push(quote!{
-            #ident_str => {
-                if let Some(array) = map.remove(#ident_str).unwrap().as_array_mut() {
+            #pattern => {
+                if let Some(array) = map.remove(&key).unwrap().as_array_mut() {
                     if array.len() == #count {
+                        let mut entries = array.drain(..);
                         Self::#ident(#(#from_unchecked_parts,)*)
                     } else {
                         panic!("Unexpected array length for enum varient '{}::{}'. Got {}, expected {}", #type_ident_str, #ident_str, array.len(), #count)
@@ -249,18 +534,15 @@ fn implement_unnamed(
             }
         });
 
+        let variant_errors = implement_variant_errors(type_ident_str, ident_str);
         validate.push(quote! {
-            if !has_key && map.contains_key(#ident_str) {
+            if let Some(variant_json) = #lookup.filter(|_| !has_key) {
                 has_key = true;
-                if let Some(array) = map.get(#ident_str).unwrap().as_array() {
+                if let Some(array) = variant_json.as_array() {
                     if array.len() == #count {
                         let mut errors = Vec::with_capacity(#count);
                         #(#validate_parts)*
-                        if errors.len() > 0 {
-                            return Err(jsonable::JsonableError::InnerErrorsForType { ty: #type_ident_str, errors})
-                        } else {
-                            return Ok(())
-                        }
+                        return #variant_errors
                     } else {
                         return Err(jsonable::JsonableError::IncorrectFieldCountForEnum{ enum_type: #type_ident_str, variant: #ident_str, count: #count})
                     }
@@ -279,31 +561,720 @@ fn implement_unnamed(
 
                 #(#to_json_parts;)*
 
-                serde_json::Value::Array(array)
+                serde_json::Value::Object(serde_json::Map::from_iter([(String::from(#ident_str), serde_json::Value::Array(array))]))
             }
         });
     } else {
         let field = unnamed.first().unwrap().clone();
         let ty = field.ty;
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        attributes.expect_plain(&format!("enum {}", type_ident_str))?;
+        let codec = attributes.codec(&ty);
+        let validate_variant = attributes.validate(&ty, quote! { variant_json });
         from_unchecked.push(quote!{
-            #ident_str => {
-                Self::#ident( <#ty as jsonable::Jsonable>::from_json_unchecked(map.remove(#ident_str).unwrap_or(serde_json::Value::Null)) )
+            #pattern => {
+                Self::#ident( #codec::from_json_unchecked(map.remove(&key).unwrap_or(serde_json::Value::Null)) )
             }
         });
         validate.push(quote! {
-            if !has_key && map.contains_key(#ident_str) {
+            if let Some(variant_json) = #lookup.filter(|_| !has_key) {
                 has_key = true;
-                let inner_json = map.get(#ident_str).unwrap();
-                match <#ty as jsonable::Jsonable>::validate_json(inner_json) {
-                    Ok(_) => {},
-                    Err(err) => return Err(
-                        jsonable::JsonableError::InnerErrorForType{ ty: #ident_str, error: jsonable::JsonableError::InnerErrorForType{ ty: std::any::type_name::<#ty>(),  error: err.into() }.into()}
-                    )
-                };
+                if let Err(err) = #validate_variant {
+                    return Err(jsonable::JsonableError::VariantValidation {
+                        variant: #ident_str,
+                        error: Box::from(jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#ty>(), error: err.into() }),
+                    })
+                }
             }
         });
-        to_json.push(quote!{Self::#ident(field1) => serde_json::Value::Object(serde_json::Map::from_iter([ (String::from(#ident_str), field1.to_json())])) });
+        to_json.push(quote!{Self::#ident(field1) => serde_json::Value::Object(serde_json::Map::from_iter([ (String::from(#ident_str), #codec::to_json(field1))])) });
     }
 
     Ok((validate, to_json, from_unchecked))
 }
+
+/// Result of the `errors` collected for the fields of a variant. A single error is reported on its
+/// own and several are collected, either way under the name of the variant
+fn implement_variant_errors(type_ident_str: &str, ident_str: &str) -> TokenStream {
+    quote! {
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(jsonable::JsonableError::VariantValidation { variant: #ident_str, error: Box::from(errors.pop().unwrap()) }),
+            _ => Err(jsonable::JsonableError::VariantValidation {
+                variant: #ident_str,
+                error: Box::from(jsonable::JsonableError::InnerErrorsForType { ty: #type_ident_str, errors }),
+            }),
+        }
+    }
+}
+
+/// `#[jsonable(tag = "...")]`
+///
+/// Variants are objects holding the variant name under `tag`, with named fields stored alongside it.
+/// Tuple variants cannot be internally tagged.
+fn implement_internally_tagged(
+    identifier: &Ident,
+    generics: &Generics,
+    tag: &String,
+    null_variant: Option<&String>,
+    rename_all: Option<RenameRule>,
+    deny_unknown_fields: bool,
+    variants: Punctuated<Variant, Comma>,
+) -> Result<TokenStream, String> {
+    let identifier_string = identifier.to_string();
+    let mut from_json_unchecked: Vec<TokenStream> = Vec::new();
+    let mut to_json: Vec<TokenStream> = Vec::new();
+    let mut validate_json: Vec<TokenStream> = Vec::new();
+    let mut expected_tags: Vec<String> = Vec::new();
+    let mut from_json_unchecked_null: Option<TokenStream> = None;
+    let mut schema_variants: Vec<TokenStream> = Vec::new();
+
+    for variant in variants.into_iter() {
+        let ident = variant.ident;
+        let variant_attributes = VariantAttributes::parse(&variant.attrs)?;
+        let ident_str = variant_attributes.name(&ident, rename_all);
+        let aliases = variant_attributes.aliases;
+        let rename_all = variant_attributes.rename_all;
+
+        match variant.fields {
+            Fields::Named(named_fields) => {
+                let mut from_unchecked_parts = Vec::new();
+                let mut to_json_parts = Vec::new();
+                let mut validate_parts = Vec::new();
+                let mut field_idents = Vec::new();
+                let mut schema_parts = Vec::new();
+                let mut keys = vec![tag.clone()];
+
+                for field in named_fields.named {
+                    let ty = field.ty;
+                    let field_ident = field.ident.unwrap();
+                    let attributes = FieldAttributes::parse(&field.attrs)?;
+                    let field_ident_str = attributes.key(&field_ident, rename_all);
+                    attributes.expect_plain(&format!("enum {}", identifier_string))?;
+                    let codec = attributes.codec(&ty);
+                    let validate = attributes.validate(&ty, quote! { value });
+
+                    from_unchecked_parts.push(quote! {
+                        #field_ident: #codec::from_json_unchecked(map.remove(#field_ident_str).unwrap_or(serde_json::Value::Null))
+                    });
+
+                    to_json_parts.push(quote! {
+                        map.insert(#field_ident_str.into(), #codec::to_json(#field_ident));
+                    });
+
+                    // Same as the externally tagged form, a missing key reads as null
+                    validate_parts.push(quote! {
+                        match map.get(#field_ident_str) {
+                            Some(value) => if let Err(err) = #validate {
+                                errors.push(jsonable::JsonableError::InvalidMapValue {
+                                    key: #field_ident_str.into(),
+                                    error: Box::from(jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#ty>(), error: err.into() }),
+                                });
+                            },
+                            None => if #codec::validate_json(&serde_json::Value::Null).is_err() {
+                                errors.push(jsonable::JsonableError::MissingKeyForEnumVariant { variant: #ident_str, key: #field_ident_str });
+                            },
+                        }
+                    });
+
+                    schema_parts.push(attributes.schema(&ty, &field_ident_str));
+                    field_idents.push(field_ident);
+                    keys.push(field_ident_str);
+                }
+
+                let validate_unknown_fields = implement_deny_unknown_fields(
+                    &identifier_string,
+                    deny_unknown_fields,
+                    quote! { map },
+                    &keys,
+                );
+
+                from_json_unchecked.push(quote! {
+                    #ident_str #(| #aliases)* => Self::#ident { #(#from_unchecked_parts,)* }
+                });
+
+                schema_variants.push(quote! {
+                    (#ident_str, Some(jsonable::schema::ObjectSchema::new() #(#schema_parts)* .build(#deny_unknown_fields)))
+                });
+
+                to_json.push(quote! {
+                    Self::#ident { #(#field_idents,)* } => {
+                        let mut map = serde_json::Map::new();
+
+                        #(#to_json_parts)*
+
+                        // Inserted after the fields, so a field sharing the tag's key cannot
+                        // replace the variant name
+                        map.insert(#tag.into(), serde_json::Value::String(#ident_str.into()));
+
+                        serde_json::Value::Object(map)
+                    }
+                });
+
+                let variant_errors = implement_variant_errors(&identifier_string, &ident_str);
+                validate_json.push(quote! {
+                    #ident_str #(| #aliases)* => {
+                        let mut errors = Vec::new();
+
+                        #(#validate_parts)*
+                        #validate_unknown_fields
+
+                        #variant_errors
+                    }
+                });
+            }
+            Fields::Unnamed(_) => {
+                return Err(format!(
+                    "Tuple variant `{}::{}` cannot be internally tagged",
+                    identifier_string, ident_str
+                ))
+            }
+            Fields::Unit if null_variant.is_some_and(|name| ident.unraw() == name) => {
+                from_json_unchecked_null =
+                    Some(quote! { serde_json::Value::Null => return Self::#ident, });
+                to_json.push(quote! { Self::#ident => serde_json::Value::Null });
+                continue;
+            }
+            Fields::Unit => {
+                from_json_unchecked.push(quote! { #ident_str #(| #aliases)* => Self::#ident });
+                to_json.push(quote! {
+                    Self::#ident => serde_json::Value::Object(serde_json::Map::from_iter([(#tag.into(), serde_json::Value::String(#ident_str.into()))]))
+                });
+                validate_json.push(quote! { #ident_str #(| #aliases)* => Ok(()) });
+                schema_variants.push(quote! { (#ident_str, None) });
+            }
+        }
+
+        expected_tags.push(ident_str);
+    }
+
+    let has_null_variant = null_variant.is_some();
+    let (validate_json_null, expected_json_type) = match null_variant {
+        Some(_) => (quote! { Ok(()) }, "object or null"),
+        None => (
+            quote! { Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "object" }) },
+            "object",
+        ),
+    };
+
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
+    Ok(quote! {
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
+            fn from_json_unchecked(json: serde_json::Value) -> Self {
+                let mut map = match json {
+                    #from_json_unchecked_null
+                    serde_json::Value::Object(map) => map,
+                    _ => panic!("Incompatible json for type '{}': {}", #identifier_string, json)
+                };
+
+                // The tag is left in the map for a field that shares its key
+                let tag = match map.get(#tag) {
+                    Some(serde_json::Value::String(tag)) => tag.clone(),
+                    _ => panic!("Missing string tag '{}' for enum '{}'", #tag, #identifier_string)
+                };
+
+                match tag.as_str() {
+                    #(#from_json_unchecked,)*
+                    other => panic!("Unknown variant of enum '{}': {}", #identifier_string, other)
+                }
+            }
+
+            fn to_json(&self) -> serde_json::Value {
+                match self {
+                    #(#to_json,)*
+                }
+            }
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    jsonable::schema::internally_tagged(#tag, vec![#(#schema_variants,)*], #has_null_variant)
+                })
+            }
+
+            fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
+                match json {
+                    serde_json::Value::Object(map) => {
+                        // Check the tag against every known variant before looking at any fields
+                        let tag = match map.get(#tag) {
+                            Some(serde_json::Value::String(tag)) => tag.as_str(),
+                            Some(other) => return Err(jsonable::JsonableError::InvalidEnumStringVariant { enum_type: #identifier_string, got: other.to_string(), expected: vec![#(#expected_tags,)*] }),
+                            None => return Err(jsonable::JsonableError::MissingEnumTag { ty: #identifier_string, tag: #tag })
+                        };
+
+                        match tag {
+                            #(#validate_json,)*
+                            other => Err(jsonable::JsonableError::InvalidEnumStringVariant { enum_type: #identifier_string, got: other.into(), expected: vec![#(#expected_tags,)*] })
+                        }
+                    },
+                    serde_json::Value::Null => #validate_json_null,
+                    serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: #expected_json_type }),
+                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: #expected_json_type }),
+                    serde_json::Value::Number(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: #expected_json_type }),
+                    serde_json::Value::String(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "string", expected: #expected_json_type }),
+                }
+            }
+        }
+    })
+}
+
+/// Inherent `json_variant_tag` returning the tag written for the current variant
+fn implement_variant_tag(
+    identifier: &Ident,
+    generics: &Generics,
+    rename_all: Option<RenameRule>,
+    variants: &Punctuated<Variant, Comma>,
+) -> Result<TokenStream, String> {
+    let mut arms = Vec::new();
+    for variant in variants {
+        let ident = &variant.ident;
+        let ident_str = VariantAttributes::parse(&variant.attrs)?.name(ident, rename_all);
+        arms.push(quote! { Self::#ident { .. } => #ident_str });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #identifier #ty_generics #where_clause {
+            /// Variant name written to json for `self`, ignoring any aliases
+            pub fn json_variant_tag(&self) -> &'static str {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
+
+/// `#[jsonable(tag_path = "/...")]`
+///
+/// Statements moving `json` to the JSON pointer when reading and validating, and nesting it back
+/// under each segment of the pointer when writing. All three are empty without a `tag_path`.
+fn implement_tag_path(
+    identifier: &Ident,
+    tag_path: Option<&str>,
+) -> Result<(TokenStream, TokenStream, TokenStream), String> {
+    let path = match tag_path {
+        Some(path) => path,
+        None => return Ok((quote! {}, quote! {}, quote! {})),
+    };
+    if !path.starts_with('/') {
+        return Err(format!(
+            "`tag_path` of enum {} must be a JSON pointer starting with `/`",
+            identifier
+        ));
+    }
+
+    let identifier_string = identifier.to_string();
+    let segments = path[1..]
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .rev();
+
+    let unwrap = quote! {
+        let mut json = json
+            .pointer_mut(#path)
+            .map(serde_json::Value::take)
+            .unwrap_or_else(|| panic!("Missing '{}' for enum '{}'", #path, #identifier_string));
+    };
+    let validate = quote! {
+        let json = match json.pointer(#path) {
+            Some(json) => json,
+            None => return Err(jsonable::JsonableError::MissingEnumTag { ty: #identifier_string, tag: #path }),
+        };
+    };
+    let wrap = quote! {
+        #(let json = serde_json::Value::Object(serde_json::Map::from_iter([(String::from(#segments), json)]));)*
+    };
+
+    Ok((unwrap, validate, wrap))
+}
+
+/// `#[jsonable(deny_unknown_fields)]`
+///
+/// Pushes an error for every key of a struct variant's object that names none of its fields.
+fn implement_deny_unknown_fields(
+    type_ident_str: &str,
+    deny_unknown_fields: bool,
+    map: TokenStream,
+    keys: &[String],
+) -> Option<TokenStream> {
+    deny_unknown_fields.then(|| {
+        quote! {
+            for key in #map.keys() {
+                if ![#(#keys),*].contains(&key.as_str()) {
+                    errors.push(jsonable::JsonableError::UnknownField { ty: #type_ident_str, field: key.clone() });
+                }
+            }
+        }
+    })
+}
+
+/// `#[jsonable(numeric)]`
+///
+/// Every variant must be a unit variant, stored as its discriminant, explicit or implicit.
+/// Discriminants are compared as `i64`.
+fn implement_numeric(
+    identifier: &Ident,
+    generics: &Generics,
+    variants: Punctuated<Variant, Comma>,
+) -> Result<TokenStream, String> {
+    let identifier_string = identifier.to_string();
+    let mut idents: Vec<Ident> = Vec::new();
+
+    for variant in variants.into_iter() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(format!(
+                "Variant `{}::{}` of a `numeric` enum must be a unit variant",
+                identifier_string, variant.ident
+            ));
+        }
+        idents.push(variant.ident);
+    }
+
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
+    Ok(quote! {
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
+            fn from_json_unchecked(json: serde_json::Value) -> Self {
+                let discriminant = json
+                    .as_i64()
+                    .unwrap_or_else(|| panic!("Tried converting non-integer json to enum '{}': {}", #identifier_string, json));
+                #(
+                    if discriminant == Self::#idents as i64 {
+                        return Self::#idents;
+                    }
+                )*
+                panic!("Unknown discriminant of enum '{}': {}", #identifier_string, discriminant)
+            }
+
+            fn to_json(&self) -> serde_json::Value {
+                match self {
+                    #(Self::#idents => serde_json::Value::from(Self::#idents as i64),)*
+                }
+            }
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    serde_json::json!({ "type": "integer", "enum": vec![#(Self::#idents as i64,)*] })
+                })
+            }
+
+            fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
+                match json {
+                    serde_json::Value::Number(number) => match number.as_i64() {
+                        #(Some(discriminant) if discriminant == Self::#idents as i64 => Ok(()),)*
+                        _ => Err(jsonable::JsonableError::InvalidEnumDiscriminant {
+                            enum_type: #identifier_string,
+                            got: number.clone(),
+                            expected: vec![#(Self::#idents as i64,)*],
+                        }),
+                    },
+                    serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: "number" }),
+                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "number" }),
+                    serde_json::Value::Null => Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "number" }),
+                    serde_json::Value::Object(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "object", expected: "number" }),
+                    serde_json::Value::String(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "string", expected: "number" }),
+                }
+            }
+        }
+    })
+}
+
+/// `#[jsonable(untagged)]`
+///
+/// Variants are stored as their bare content: unit variants as `null`, single field tuple
+/// variants as the field, other tuple variants as an array, and struct variants as an object.
+///
+/// Reading tries each variant in declaration order and takes the first whose content validates,
+/// so a variant accepting a superset of a later one's json, such as `Option<u32>` before `u32`,
+/// hides the later variant.
+fn implement_untagged(
+    identifier: &Ident,
+    generics: &Generics,
+    variants: Punctuated<Variant, Comma>,
+) -> Result<TokenStream, String> {
+    let identifier_string = identifier.to_string();
+    let mut matches: Vec<TokenStream> = Vec::new();
+    let mut from_json_unchecked: Vec<TokenStream> = Vec::new();
+    let mut to_json: Vec<TokenStream> = Vec::new();
+    let mut json_schema: Vec<TokenStream> = Vec::new();
+
+    for variant in variants.into_iter() {
+        let ident = variant.ident;
+        let variant_attributes = VariantAttributes::parse(&variant.attrs)?;
+        if !variant_attributes.aliases.is_empty() {
+            return Err(format!(
+                "`alias` cannot be used on variant {}::{} of an untagged enum",
+                identifier_string, ident
+            ));
+        }
+        let rename_all = variant_attributes.rename_all;
+        if !matches!(variant.fields, Fields::Named(_)) {
+            json_schema.push(
+                implement_payload_schema(&variant.fields, rename_all, false)?
+                    .unwrap_or_else(|| quote! { serde_json::json!({ "type": "null" }) }),
+            );
+        }
+
+        match variant.fields {
+            Fields::Unit => {
+                matches.push(quote! { json.is_null() });
+                from_json_unchecked.push(quote! { Self::#ident });
+                to_json.push(quote! { Self::#ident => serde_json::Value::Null });
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let field = fields.unnamed.first().unwrap();
+                let attributes = FieldAttributes::parse(&field.attrs)?;
+                attributes.expect_plain(&format!("enum {}", identifier_string))?;
+                let codec = attributes.codec(&field.ty);
+                let validate = attributes.validate(&field.ty, quote! { json });
+                matches.push(quote! { #validate.is_ok() });
+                from_json_unchecked
+                    .push(quote! { Self::#ident(#codec::from_json_unchecked(json)) });
+                to_json.push(quote! { Self::#ident(field0) => #codec::to_json(field0) });
+            }
+            Fields::Unnamed(fields) => {
+                let count = fields.unnamed.len();
+                let mut codecs = Vec::with_capacity(count);
+                let mut validates = Vec::with_capacity(count);
+                for (idx, field) in fields.unnamed.iter().enumerate() {
+                    let attributes = FieldAttributes::parse(&field.attrs)?;
+                    attributes.expect_plain(&format!("enum {}", identifier_string))?;
+                    codecs.push(attributes.codec(&field.ty));
+                    validates.push(attributes.validate(&field.ty, quote! { &array[#idx] }));
+                }
+                let field_idents: Vec<Ident> = (0..count)
+                    .map(|idx| Ident::new(&format!("field{}", idx), ident.span()))
+                    .collect();
+
+                matches.push(quote! {
+                    match json {
+                        serde_json::Value::Array(array) if array.len() == #count => true
+                            #(&& #validates.is_ok())*,
+                        _ => false,
+                    }
+                });
+                from_json_unchecked.push(quote! {{
+                    let mut entries = match json {
+                        serde_json::Value::Array(array) => array.into_iter(),
+                        _ => unreachable!(),
+                    };
+                    Self::#ident(#(#codecs::from_json_unchecked(entries.next().unwrap()),)*)
+                }});
+                to_json.push(quote! {
+                    Self::#ident(#(#field_idents,)*) => serde_json::Value::Array(vec![#(#codecs::to_json(#field_idents),)*])
+                });
+            }
+            Fields::Named(fields) => {
+                let mut validate_parts = Vec::new();
+                let mut from_unchecked_parts = Vec::new();
+                let mut to_json_parts = Vec::new();
+                let mut field_idents = Vec::new();
+                let mut schema_parts = Vec::new();
+
+                for field in fields.named {
+                    let ty = field.ty;
+                    let field_ident = field.ident.unwrap();
+                    let attributes = FieldAttributes::parse(&field.attrs)?;
+                    attributes.expect_plain(&format!("enum {}", identifier_string))?;
+                    let key = attributes.key(&field_ident, rename_all);
+                    let codec = attributes.codec(&ty);
+
+                    validate_parts.push(attributes.validate(
+                        &ty,
+                        quote! { map.get(#key).unwrap_or(&serde_json::Value::Null) },
+                    ));
+                    from_unchecked_parts.push(quote! {
+                        #field_ident: #codec::from_json_unchecked(map.remove(#key).unwrap_or(serde_json::Value::Null))
+                    });
+                    to_json_parts.push(quote! {
+                        map.insert(#key.into(), #codec::to_json(#field_ident));
+                    });
+                    schema_parts.push(attributes.schema(&ty, &key));
+                    field_idents.push(field_ident);
+                }
+
+                json_schema.push(quote! {
+                    jsonable::schema::ObjectSchema::new() #(#schema_parts)* .build(false)
+                });
+
+                matches.push(if validate_parts.is_empty() {
+                    quote! { json.is_object() }
+                } else {
+                    quote! {
+                        match json {
+                            serde_json::Value::Object(map) => true #(&& #validate_parts.is_ok())*,
+                            _ => false,
+                        }
+                    }
+                });
+                from_json_unchecked.push(quote! {{
+                    let mut map = match json {
+                        serde_json::Value::Object(map) => map,
+                        _ => unreachable!(),
+                    };
+                    Self::#ident { #(#from_unchecked_parts,)* }
+                }});
+                to_json.push(quote! {
+                    Self::#ident { #(#field_idents,)* } => {
+                        let mut map = serde_json::Map::new();
+                        #(#to_json_parts)*
+                        serde_json::Value::Object(map)
+                    }
+                });
+            }
+        }
+    }
+
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
+    Ok(quote! {
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
+            fn from_json_unchecked(json: serde_json::Value) -> Self {
+                #(
+                    if { let json = &json; #matches } {
+                        return #from_json_unchecked;
+                    }
+                )*
+                panic!("Json matches no variant of untagged enum '{}': {}", #identifier_string, json)
+            }
+
+            fn to_json(&self) -> serde_json::Value {
+                match self {
+                    #(#to_json,)*
+                }
+            }
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    jsonable::schema::any_of(vec![#(#json_schema,)*])
+                })
+            }
+
+            fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
+                #(
+                    if #matches {
+                        return Ok(());
+                    }
+                )*
+                Err(jsonable::JsonableError::NoUntaggedVariantMatched { ty: #identifier_string })
+            }
+        }
+    })
+}
+
+/// `#[jsonable(tag = "...", content = "...")]`
+///
+/// Variants are objects holding the variant name under `tag`, and whatever the externally tagged
+/// form stores under the variant name under `content`. Unit variants have no `content`.
+///
+/// The returned statements convert `json` to and from the externally tagged form when reading and
+/// writing, in the same places as [implement_tag_path]. Validation checks `content` where it is,
+/// under the variant named by `tag`.
+fn implement_adjacent_tag(
+    identifier: &Ident,
+    tag: &str,
+    content: &str,
+    null_variant: Option<&String>,
+) -> (TokenStream, TokenStream, TokenStream) {
+    let identifier_string = identifier.to_string();
+    let validate_null = match null_variant {
+        Some(_) => quote! { serde_json::Value::Null => json, },
+        None => quote! {
+            serde_json::Value::Null => return Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "object" }),
+        },
+    };
+
+    let unwrap = quote! {
+        let mut json = match json {
+            serde_json::Value::Object(mut map) => {
+                let tag = match map.remove(#tag) {
+                    Some(serde_json::Value::String(tag)) => tag,
+                    _ => panic!("Missing '{}' for enum '{}'", #tag, #identifier_string),
+                };
+                match map.remove(#content) {
+                    Some(content) => serde_json::Value::Object(serde_json::Map::from_iter([(tag, content)])),
+                    None => serde_json::Value::String(tag),
+                }
+            }
+            json => json,
+        };
+    };
+    let validate = quote! {
+        let json = match json {
+            serde_json::Value::Object(map) => {
+                let tag = match map.get(#tag) {
+                    Some(serde_json::Value::String(tag)) => tag.as_str(),
+                    _ => return Err(jsonable::JsonableError::MissingEnumTag { ty: #identifier_string, tag: #tag }),
+                };
+                return match map.get(#content) {
+                    Some(content) => validate_object(tag, content),
+                    None => validate_string(tag),
+                };
+            }
+            #validate_null
+            serde_json::Value::Array(_) => return Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: "object" }),
+            serde_json::Value::Bool(_) => return Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "object" }),
+            serde_json::Value::Number(_) => return Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: "object" }),
+            serde_json::Value::String(_) => return Err(jsonable::JsonableError::IncompatibleJsonType { got: "string", expected: "object" }),
+        };
+    };
+    let wrap = quote! {
+        let json = match json {
+            serde_json::Value::String(variant) => serde_json::Value::Object(serde_json::Map::from_iter([
+                (String::from(#tag), serde_json::Value::String(variant)),
+            ])),
+            serde_json::Value::Object(map) => {
+                let (variant, content) = map.into_iter().next().unwrap();
+                serde_json::Value::Object(serde_json::Map::from_iter([
+                    (String::from(#tag), serde_json::Value::String(variant)),
+                    (String::from(#content), content),
+                ]))
+            }
+            json => json,
+        };
+    };
+
+    (unwrap, validate, wrap)
+}
+
+/// `#[jsonable(null_variant = "...")]`
+///
+/// The named variant must exist and be a unit variant, since it is stored as a bare `null`.
+fn check_null_variant(
+    identifier: &Ident,
+    null_variant: Option<&String>,
+    variants: &Punctuated<Variant, Comma>,
+) -> Result<(), String> {
+    let null_variant = match null_variant {
+        Some(null_variant) => null_variant,
+        None => return Ok(()),
+    };
+
+    match variants
+        .iter()
+        .find(|variant| variant.ident.unraw() == null_variant)
+    {
+        Some(Variant {
+            fields: Fields::Unit,
+            ..
+        }) => Ok(()),
+        Some(_) => Err(format!(
+            "`null_variant` {}::{} must be a unit variant",
+            identifier, null_variant
+        )),
+        None => Err(format!(
+            "`null_variant` {} is not a variant of {}",
+            null_variant, identifier
+        )),
+    }
+}
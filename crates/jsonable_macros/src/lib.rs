@@ -1,35 +1,84 @@
-use syn::{parse_macro_input, Data, DataEnum, DataStruct, DeriveInput, Fields};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, Data, DataEnum, DataStruct, DeriveInput, Fields,
+    Generics,
+};
 
+mod attributes;
 mod enums;
 mod structs;
 
-#[proc_macro_derive(Jsonable)]
+#[proc_macro_derive(Jsonable, attributes(jsonable))]
 pub fn derive_jsonable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let attributes = match attributes::ContainerAttributes::parse(&input.attrs) {
+        Ok(attributes) => attributes,
+        Err(err) => panic!("{}", err),
+    };
     match input.data {
         Data::Struct(DataStruct {
             fields: Fields::Named(fields),
             ..
-        }) => match structs::implement_named(&input.ident, fields) {
+        }) => match structs::implement_named(
+            &input.ident,
+            &input.vis,
+            &input.generics,
+            &attributes,
+            fields,
+        ) {
             Ok(output) => output,
             Err(err) => panic!("{}", err),
         },
         Data::Struct(DataStruct {
             fields: Fields::Unit,
             ..
-        }) => structs::implement_unit(&input.ident),
-        Data::Struct(DataStruct {
-            fields: Fields::Unnamed(fields),
-            ..
-        }) => match structs::implement_unnamed(&input.ident, fields) {
-            Ok(output) => output,
-            Err(err) => panic!("{}", err),
-        },
-        Data::Enum(DataEnum { variants, .. }) => match enums::implement(&input.ident, variants) {
+        }) => match structs::implement_unit(&input.ident, &input.generics, &attributes) {
             Ok(output) => output,
             Err(err) => panic!("{}", err),
         },
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) => {
+            match structs::implement_unnamed(&input.ident, &input.generics, &attributes, fields) {
+                Ok(output) => output,
+                Err(err) => panic!("{}", err),
+            }
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            match enums::implement(&input.ident, &input.generics, &attributes, variants) {
+                Ok(output) => output,
+                Err(err) => panic!("{}", err),
+            }
+        }
         Data::Union(_) => panic!("Jsonable does not support unions"),
     }
     .into()
 }
+
+/// Copies `generics`, requiring every type parameter to implement `Jsonable`
+fn with_jsonable_bounds(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(jsonable::Jsonable));
+    }
+    generics
+}
+
+/// `Jsonable::schema_ref_name` of a derived type, its own identifier.
+///
+/// Types with type or const parameters keep the default `None`: every instantiation has its own
+/// schema, which a single name cannot refer to.
+fn implement_schema_ref_name(identifier: &Ident, generics: &Generics) -> TokenStream {
+    if generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+        return TokenStream::new();
+    }
+
+    let name = identifier.unraw().to_string();
+    quote! {
+        fn schema_ref_name() -> Option<&'static str> {
+            Some(#name)
+        }
+    }
+}
@@ -1,43 +1,233 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::{FieldsNamed, FieldsUnnamed};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    ext::IdentExt, parse_quote, spanned::Spanned, FieldsNamed, FieldsUnnamed, Generics, Visibility,
+};
+
+use crate::{
+    attributes::{ContainerAttributes, FieldAttributes, RenameRule},
+    implement_schema_ref_name, with_jsonable_bounds,
+};
+
+pub fn implement_named(
+    identifier: &Ident,
+    visibility: &Visibility,
+    generics: &Generics,
+    attributes: &ContainerAttributes,
+    input: FieldsNamed,
+) -> Result<TokenStream, String> {
+    attributes.expect_struct(identifier)?;
+    if attributes.as_entry || attributes.transparent {
+        return Err(format!(
+            "`as_entry` and `transparent` cannot be used on named struct {}",
+            identifier
+        ));
+    }
+    let patch = if attributes.generate_patch {
+        implement_patch(
+            identifier,
+            visibility,
+            generics,
+            attributes.rename_all,
+            &input,
+        )?
+    } else {
+        TokenStream::new()
+    };
 
-pub fn implement_named(identifier: &Ident, input: FieldsNamed) -> Result<TokenStream, String> {
     let mut from_json_unchecked: Vec<TokenStream> = Vec::new();
+    let mut from_json_fused: Vec<TokenStream> = Vec::new();
+    let mut fused_fields: Vec<TokenStream> = Vec::new();
     let mut to_json: Vec<TokenStream> = Vec::new();
+    let mut into_json: Vec<TokenStream> = Vec::new();
     let mut validate_json: Vec<TokenStream> = Vec::new();
+    let mut json_schema: Vec<TokenStream> = Vec::new();
+    // Flattened fields are read after every other field took its own key
+    let mut from_json_unchecked_flattened: Vec<TokenStream> = Vec::new();
+    let mut from_json_fused_flattened: Vec<TokenStream> = Vec::new();
+
+    let fields = input
+        .named
+        .into_iter()
+        .map(|field| Ok((FieldAttributes::parse(&field.attrs)?, field)))
+        .collect::<Result<Vec<_>, String>>()?;
+    let rename_all = attributes.rename_all;
+    check_flatten_prefixes(identifier, rename_all, &fields)?;
+    let deny_unknown_fields = attributes.deny_unknown_fields;
+    let (validate_unknown_fields, fused_unknown_fields) = if deny_unknown_fields {
+        implement_deny_unknown_fields(identifier, rename_all, &fields)?
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
 
-    for field in input.named.into_iter() {
+    for (attributes, field) in fields {
         let ident = field.ident.unwrap();
-        let ident_str = ident.to_string();
+        let ident_str = attributes.key(&ident, rename_all);
         let ty = field.ty;
 
-        from_json_unchecked.push(quote! {
-            #ident: <#ty as jsonable::Jsonable>::from_json_unchecked(inner_json.remove(#ident_str).unwrap_or(serde_json::Value::Null)),
+        if attributes.skip {
+            // Spanned to the field's type, so a missing `Default` impl is reported there
+            let default = attributes
+                .default
+                .clone()
+                .unwrap_or_else(|| quote_spanned! { ty.span()=> <#ty as Default>::default() });
+            from_json_unchecked.push(quote! { #ident: #default, });
+            fused_fields.push(quote! { #ident: #default, });
+            continue;
+        }
+
+        let codec = attributes.codec(&ty);
+        let prefix = attributes.prefix.clone().unwrap_or_default();
+        let get = attributes.get(quote! { map }, &ident_str);
+        let remove = attributes.remove(quote! { map }, &ident_str);
+        let remove_unchecked = attributes.remove(quote! { inner_json }, &ident_str);
+
+        let (validate, fused, unchecked) = if attributes.flatten {
+            (
+                attributes.validate(&ty, quote! { &jsonable::flatten::select(map, #prefix) }),
+                attributes.fused(&ty, quote! { jsonable::flatten::take(&mut map, #prefix) }),
+                quote! { #codec::from_json_unchecked(jsonable::flatten::take(inner_json, #prefix)) },
+            )
+        } else if let Some(default) = &attributes.default {
+            // An absent key skips the field's checks entirely, a present one is checked as usual
+            let validate = attributes.validate(&ty, quote! { value });
+            let fused = attributes.fused(&ty, quote! { value });
+            (
+                quote! {
+                    match #get {
+                        Some(value) => #validate,
+                        None => Ok(()),
+                    }
+                },
+                quote! {
+                    match #remove {
+                        Some(value) => #fused,
+                        None => Ok(#default),
+                    }
+                },
+                quote! {
+                    match #remove_unchecked {
+                        Some(value) => #codec::from_json_unchecked(value),
+                        None => #default,
+                    }
+                },
+            )
+        } else {
+            (
+                attributes.validate(&ty, quote! { #get.unwrap_or(&serde_json::Value::Null) }),
+                attributes.fused(&ty, quote! { #remove.unwrap_or(serde_json::Value::Null) }),
+                quote! { #codec::from_json_unchecked(#remove_unchecked.unwrap_or(serde_json::Value::Null)) },
+            )
+        };
+
+        let unchecked = quote! {
+            #ident: #unchecked,
+        };
+        if attributes.flatten {
+            from_json_unchecked_flattened.push(unchecked);
+        } else {
+            from_json_unchecked.push(unchecked);
+        }
+
+        // Every field is converted even after one failed, so the errors match `validate_json`
+        let local = format_ident!("__jsonable_{}", ident.unraw());
+        let from_json_fused = if attributes.flatten {
+            &mut from_json_fused_flattened
+        } else {
+            &mut from_json_fused
+        };
+        // Flattened fields have no key of their own, their errors name the keys they read
+        let key = match attributes.flatten {
+            true => quote! { None },
+            false => quote! { Some(#ident_str) },
+        };
+        from_json_fused.push(quote! {
+            let #local = match #fused {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    errors.push((#key, jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#ty>(), error: Box::from(err)}));
+                    None
+                }
+            };
         });
+        fused_fields.push(quote! { #ident: #local.unwrap(), });
 
         validate_json.push(quote!{
-            match <#ty as jsonable::Jsonable>::validate_json(map.get(#ident_str).unwrap_or(&serde_json::Value::Null)) {
-                Ok(()) => (),
-                Err(err) => return Err(jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#ty>(), error: Box::from(err)})
+            if let Err(err) = #validate {
+                errors.push((#key, jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#ty>(), error: Box::from(err)}));
             }
         });
 
-        to_json.push(quote! {
-            map.insert(#ident_str.into(), self.#ident.to_json());
-        });
+        json_schema.push(attributes.schema(&ty, &ident_str));
+
+        let owned = attributes.owned_json(&ty, quote! { self.#ident });
+        if attributes.flatten {
+            to_json.push(quote! {
+                jsonable::flatten::insert(&mut map, #prefix, #codec::to_json(&self.#ident));
+            });
+            into_json.push(quote! {
+                jsonable::flatten::insert(&mut map, #prefix, #owned);
+            });
+        } else {
+            to_json.push(quote! {
+                map.insert(#ident_str.into(), #codec::to_json(&self.#ident));
+            });
+            into_json.push(quote! {
+                map.insert(#ident_str.into(), #owned);
+            });
+        }
     }
 
     let ident_str = identifier.to_string();
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let collect_errors = implement_collect_errors(&ident_str);
+
+    let into_json = attributes.into_json.then(|| {
+        quote! {
+            fn into_json(self) -> serde_json::Value {
+                let mut map = serde_json::Map::new();
+
+                #(#into_json)*
+
+                serde_json::Value::Object(map)
+            }
+        }
+    });
+
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
     Ok(quote! {
-        impl jsonable::Jsonable for #identifier {
+        #patch
+
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
             fn from_json_unchecked(mut json: serde_json::Value) -> Self {
                 let mut inner_json = json
                     .as_object_mut()
                     .unwrap_or_else(|| panic!("Tried converting non-object json to {}", #ident_str));
                 Self {
                     #(#from_json_unchecked)*
+                    #(#from_json_unchecked_flattened)*
+                }
+            }
+
+            fn from_json_fused(json: serde_json::Value) -> jsonable::Result<Self> {
+                match json {
+                    serde_json::Value::Object(mut map) => {
+                        let mut errors: Vec<(Option<&'static str>, jsonable::JsonableError)> = Vec::new();
+
+                        #(#from_json_fused)*
+                        #(#from_json_fused_flattened)*
+                        #fused_unknown_fields
+
+                        let result: jsonable::Result<()> = #collect_errors;
+                        result.map(|_| Self {
+                            #(#fused_fields)*
+                        })
+                    }
+                    json => Self::from_json(json),
                 }
             }
 
@@ -49,12 +239,25 @@ pub fn implement_named(identifier: &Ident, input: FieldsNamed) -> Result<TokenSt
                 serde_json::Value::Object(map)
             }
 
+            #into_json
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    jsonable::schema::ObjectSchema::new()
+                        #(#json_schema)*
+                        .build(#deny_unknown_fields)
+                })
+            }
+
             fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
                 match json {
                     serde_json::Value::Object(map) => {
+                        let mut errors: Vec<(Option<&'static str>, jsonable::JsonableError)> = Vec::new();
+
                         #(#validate_json)*
+                        #validate_unknown_fields
 
-                        Ok(())
+                        #collect_errors
                     },
                     serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: "object" }),
                     serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "object" }),
@@ -67,76 +270,317 @@ pub fn implement_named(identifier: &Ident, input: FieldsNamed) -> Result<TokenSt
     })
 }
 
-pub fn implement_unnamed(identifier: &Ident, input: FieldsUnnamed) -> Result<TokenStream, String> {
+/// Result of the `(key, error)` pairs collected in `errors`. Each field error is reported under
+/// the key of its field. A single bad field is reported on its own, several are collected
+fn implement_collect_errors(ident_str: &str) -> TokenStream {
+    quote! {
+        {
+            let mut errors: Vec<jsonable::JsonableError> = errors
+                .into_iter()
+                .map(|(key, error)| match key {
+                    Some(key) => jsonable::JsonableError::InvalidMapValue { key: key.into(), error: Box::from(error) },
+                    None => error,
+                })
+                .collect();
+            match errors.len() {
+                0 => Ok(()),
+                1 => Err(errors.pop().unwrap()),
+                _ => Err(jsonable::JsonableError::InnerErrorsForType { ty: #ident_str, errors }),
+            }
+        }
+    }
+}
+
+/// `#[jsonable(flatten, prefix = "...")]`
+///
+/// Errors if two flattened fields claim overlapping prefixes, or a field's own key starts with
+/// a flattened field's prefix. Only keys known to the derive are checked, since the keys of the
+/// flattened types are not.
+fn check_flatten_prefixes(
+    identifier: &Ident,
+    rename_all: Option<RenameRule>,
+    fields: &[(FieldAttributes, syn::Field)],
+) -> Result<(), String> {
+    let prefixes: Vec<&String> = fields
+        .iter()
+        .filter(|(attributes, _)| attributes.flatten)
+        .filter_map(|(attributes, _)| attributes.prefix.as_ref())
+        .filter(|prefix| !prefix.is_empty())
+        .collect();
+
+    for (index, prefix) in prefixes.iter().enumerate() {
+        if let Some(other) = prefixes[index + 1..]
+            .iter()
+            .find(|other| other.starts_with(prefix.as_str()) || prefix.starts_with(other.as_str()))
+        {
+            return Err(format!(
+                "Flattened prefixes `{}` and `{}` of struct {} overlap",
+                prefix, other, identifier
+            ));
+        }
+    }
+
+    for (attributes, field) in fields
+        .iter()
+        .filter(|(attributes, _)| !attributes.flatten && !attributes.skip)
+    {
+        let key = attributes.key(field.ident.as_ref().unwrap(), rename_all);
+        for key in std::iter::once(&key).chain(&attributes.aliases) {
+            if let Some(prefix) = prefixes
+                .iter()
+                .find(|prefix| key.starts_with(prefix.as_str()))
+            {
+                return Err(format!(
+                    "Field `{}` of struct {} collides with the flattened prefix `{}`",
+                    key, identifier, prefix
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `#[jsonable(deny_unknown_fields)]`
+///
+/// Returns the statements pushing a `JsonableError::UnknownField` for every unclaimed key, for
+/// `validate_json` and for `from_json_fused`. The latter runs after every field removed its keys
+/// from the map, so whatever is left is unknown.
+fn implement_deny_unknown_fields(
+    identifier: &Ident,
+    rename_all: Option<RenameRule>,
+    fields: &[(FieldAttributes, syn::Field)],
+) -> Result<(TokenStream, TokenStream), String> {
+    let mut keys = Vec::new();
+    let mut prefixes = Vec::new();
+
+    for (attributes, field) in fields.iter().filter(|(attributes, _)| !attributes.skip) {
+        match attributes.prefix.as_deref() {
+            _ if !attributes.flatten => {
+                keys.push(attributes.key(field.ident.as_ref().unwrap(), rename_all));
+                keys.extend(attributes.aliases.iter().cloned());
+            }
+            Some(prefix) if !prefix.is_empty() => prefixes.push(prefix.to_string()),
+            _ => {
+                return Err(format!(
+                "`deny_unknown_fields` requires a `prefix` on every flattened field of struct {}",
+                identifier
+            ))
+            }
+        }
+    }
+
+    let ident_str = identifier.to_string();
+    let validate = quote! {
+        for key in map.keys() {
+            let known = [#(#keys),*].contains(&key.as_str());
+            let prefixed = [#(#prefixes),*].iter().any(|prefix: &&str| key.starts_with(prefix));
+            if !known && !prefixed {
+                errors.push((None, jsonable::JsonableError::UnknownField { ty: #ident_str, field: key.clone() }));
+            }
+        }
+    };
+    let fused = quote! {
+        for key in map.keys() {
+            errors.push((None, jsonable::JsonableError::UnknownField { ty: #ident_str, field: key.clone() }));
+        }
+    };
+
+    Ok((validate, fused))
+}
+
+/// Tuple structs are json arrays with one entry per field, in order, unless `as_entry` or
+/// `transparent` is set.
+pub fn implement_unnamed(
+    identifier: &Ident,
+    generics: &Generics,
+    attributes: &ContainerAttributes,
+    input: FieldsUnnamed,
+) -> Result<TokenStream, String> {
+    attributes.expect_struct(identifier)?;
+    if attributes.generate_patch {
+        return Err(format!(
+            "`generate_patch` cannot be used on tuple struct {}",
+            identifier
+        ));
+    }
+    if attributes.deny_unknown_fields {
+        return Err(format!(
+            "`deny_unknown_fields` cannot be used on tuple struct {}",
+            identifier
+        ));
+    }
+    if attributes.rename_all.is_some() {
+        return Err(format!(
+            "`rename_all` cannot be used on tuple struct {}",
+            identifier
+        ));
+    }
+    if attributes.as_entry && attributes.transparent {
+        return Err(format!(
+            "`as_entry` and `transparent` cannot both be used on tuple struct {}",
+            identifier
+        ));
+    }
+    if attributes.as_entry && attributes.into_json {
+        return Err(format!(
+            "`as_entry` and `into_json` cannot both be used on tuple struct {}",
+            identifier
+        ));
+    }
+    if attributes.as_entry {
+        return implement_entry(identifier, generics, input);
+    }
+    if attributes.transparent {
+        return implement_transparent(identifier, generics, attributes.into_json, input);
+    }
+
+    let count = input.unnamed.len();
     let mut from_json_unchecked: Vec<TokenStream> = Vec::new();
+    let mut from_json_fused: Vec<TokenStream> = Vec::new();
     let mut to_json: Vec<TokenStream> = Vec::new();
+    let mut into_json: Vec<TokenStream> = Vec::new();
     let mut validate_json: Vec<TokenStream> = Vec::new();
+    let mut json_schema: Vec<TokenStream> = Vec::new();
 
     for (idx, field) in input.unnamed.into_iter().enumerate() {
-        let ident_str = idx.to_string();
         let ty = field.ty;
-
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        attributes.expect_plain(&format!("tuple struct {}", identifier))?;
+        let codec = attributes.codec(&ty);
+        let validate = attributes.validate(&ty, quote! { &values[#idx] });
+        let fused = attributes.fused(&ty, quote! { values.next().unwrap() });
         let index = syn::Index::from(idx);
 
         from_json_unchecked.push(quote! {
-            #index: <#ty as jsonable::Jsonable>::from_json_unchecked(inner_json.remove(#ident_str).unwrap_or(serde_json::Value::Null)),
+            #codec::from_json_unchecked(values.next().unwrap()),
         });
 
-        validate_json.push(quote!{
-            match <#ty as jsonable::Jsonable>::validate_json(map.get(#ident_str).unwrap_or(&serde_json::Value::Null)) {
-                Ok(()) => (),
-                Err(err) => return Err(jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#ty>(), error: Box::from(err)})
-            }
+        from_json_fused.push(quote! {
+            match #fused {
+                Ok(value) => value,
+                Err(err) => return Err(jsonable::JsonableError::IncompatibleEntryAt { index: #idx, error: Box::from(err) })
+            },
         });
 
-        to_json.push(quote! {
-            map.insert(#ident_str.into(), self.#index.to_json());
+        validate_json.push(quote! {
+            if let Err(err) = #validate {
+                return Err(jsonable::JsonableError::IncompatibleEntryAt { index: #idx, error: Box::from(err) });
+            }
         });
+
+        to_json.push(quote! { #codec::to_json(&self.#index), });
+
+        json_schema.push(attributes.json_schema(&ty));
+
+        let owned = attributes.owned_json(&ty, quote! { self.#index });
+        into_json.push(quote! { #owned, });
     }
 
     let ident_str = identifier.to_string();
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let into_json = attributes.into_json.then(|| {
+        quote! {
+            fn into_json(self) -> serde_json::Value {
+                serde_json::Value::Array(vec![#(#into_json)*])
+            }
+        }
+    });
 
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
     Ok(quote! {
-        impl jsonable::Jsonable for #identifier {
-            fn from_json_unchecked(mut json: serde_json::Value) -> Self {
-                let mut inner_json = json
-                    .as_object_mut()
-                    .unwrap_or_else(|| panic!("Tried converting non-object json to {}", #ident_str));
-                Self {
-                    #(#from_json_unchecked)*
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
+            fn from_json_unchecked(json: serde_json::Value) -> Self {
+                let mut values = match json {
+                    serde_json::Value::Array(values) if values.len() == #count => values.into_iter(),
+                    _ => panic!("Tried converting json other than an array of length {} to {}", #count, #ident_str),
+                };
+                Self(#(#from_json_unchecked)*)
+            }
+
+            fn from_json_fused(json: serde_json::Value) -> jsonable::Result<Self> {
+                match json {
+                    serde_json::Value::Array(values) if values.len() == #count => {
+                        let mut values = values.into_iter();
+                        Ok(Self(#(#from_json_fused)*))
+                    }
+                    json => Self::from_json(json),
                 }
             }
 
             fn to_json(&self) -> serde_json::Value {
-                let mut map = serde_json::Map::new();
+                serde_json::Value::Array(vec![#(#to_json)*])
+            }
 
-                #(#to_json)*
+            #into_json
 
-                serde_json::Value::Object(map)
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    jsonable::schema::tuple(vec![#(#json_schema,)*])
+                })
             }
 
             fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
                 match json {
-                    serde_json::Value::Object(map) => {
+                    serde_json::Value::Array(values) => {
+                        if values.len() != #count {
+                            return Err(jsonable::JsonableError::InvalidArrayLength { got: values.len(), expected: #count });
+                        }
                         #(#validate_json)*
 
                         Ok(())
                     },
-                    serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: "object" }),
-                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "object" }),
-                    serde_json::Value::Null => Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "object" }),
-                    serde_json::Value::Number(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: "object" }),
-                    serde_json::Value::String(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "string", expected: "object" })
+                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "array" }),
+                    serde_json::Value::Null => Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "array" }),
+                    serde_json::Value::Number(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: "array" }),
+                    serde_json::Value::Object(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "object", expected: "array" }),
+                    serde_json::Value::String(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "string", expected: "array" })
                 }
             }
         }
     })
 }
 
-pub fn implement_unit(identifier: &Ident) -> TokenStream {
+pub fn implement_unit(
+    identifier: &Ident,
+    generics: &Generics,
+    attributes: &ContainerAttributes,
+) -> Result<TokenStream, String> {
+    attributes.expect_struct(identifier)?;
+    if attributes.as_entry
+        || attributes.transparent
+        || attributes.generate_patch
+        || attributes.into_json
+    {
+        return Err(format!(
+            "`as_entry`, `transparent`, `generate_patch`, and `into_json` cannot be used on unit struct {}",
+            identifier
+        ));
+    }
+    if attributes.deny_unknown_fields {
+        return Err(format!(
+            "`deny_unknown_fields` cannot be used on unit struct {}",
+            identifier
+        ));
+    }
+    if attributes.rename_all.is_some() {
+        return Err(format!(
+            "`rename_all` cannot be used on unit struct {}",
+            identifier
+        ));
+    }
+
     let ident_str = identifier.to_string();
-    quote! {
-        impl jsonable::Jsonable for #identifier {
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
+    Ok(quote! {
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
             fn from_json_unchecked(json: serde_json::Value) -> Self {
                 let inner_json = json
                     .as_null()
@@ -148,6 +592,12 @@ pub fn implement_unit(identifier: &Ident) -> TokenStream {
                 serde_json::Value::Null
             }
 
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    serde_json::json!({ "type": "null" })
+                })
+            }
+
             fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
                 match json {
                     serde_json::Value::Null => Ok(()),
@@ -159,5 +609,314 @@ pub fn implement_unit(identifier: &Ident) -> TokenStream {
                 }
             }
         }
+    })
+}
+
+/// `#[jsonable(as_entry)]`
+///
+/// The first field is the key and the second field the value of a single key object.
+/// The key type must implement `JsonMapKey`, the same as `HashMap` keys.
+fn implement_entry(
+    identifier: &Ident,
+    generics: &Generics,
+    input: FieldsUnnamed,
+) -> Result<TokenStream, String> {
+    if input.unnamed.len() != 2 {
+        return Err(format!(
+            "`as_entry` requires exactly two fields on {}",
+            identifier
+        ));
     }
+
+    let mut fields = input.unnamed.into_iter();
+    let key_ty = fields.next().unwrap().ty;
+    let value = fields.next().unwrap();
+    let value_ty = value.ty;
+    let attributes = FieldAttributes::parse(&value.attrs)?;
+    attributes.expect_plain(&format!("tuple struct {}", identifier))?;
+    let codec = attributes.codec(&value_ty);
+    let validate = attributes.validate(&value_ty, quote! { map.values().next().unwrap() });
+    let value_schema = attributes.json_schema(&value_ty);
+
+    let ident_str = identifier.to_string();
+    let mut generics = with_jsonable_bounds(generics);
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#key_ty: jsonable::JsonMapKey));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
+    Ok(quote! {
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
+            fn from_json_unchecked(json: serde_json::Value) -> Self {
+                let map = match json {
+                    serde_json::Value::Object(map) => map,
+                    _ => panic!("Tried converting non-object json to {}", #ident_str)
+                };
+                let (key, value) = map
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| panic!("Tried converting empty object json to {}", #ident_str));
+
+                Self(<#key_ty as jsonable::JsonMapKey>::from_key(key), #codec::from_json_unchecked(value))
+            }
+
+            fn to_json(&self) -> serde_json::Value {
+                serde_json::Value::Object(serde_json::Map::from_iter([(jsonable::JsonMapKey::to_key(&self.0), #codec::to_json(&self.1))]))
+            }
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    let mut schema = jsonable::schema::map(#value_schema);
+                    schema["minProperties"] = 1.into();
+                    schema["maxProperties"] = 1.into();
+                    schema
+                })
+            }
+
+            fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
+                match json {
+                    serde_json::Value::Object(map) => {
+                        if map.len() != 1 {
+                            return Err(jsonable::JsonableError::InvalidObjectLength { got: map.len(), expected: 1 });
+                        }
+
+                        match #validate {
+                            Ok(()) => Ok(()),
+                            Err(err) => Err(jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#value_ty>(), error: Box::from(err)})
+                        }
+                    },
+                    serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: "object" }),
+                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "object" }),
+                    serde_json::Value::Null => Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "object" }),
+                    serde_json::Value::Number(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: "object" }),
+                    serde_json::Value::String(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "string", expected: "object" })
+                }
+            }
+        }
+    })
+}
+
+/// `#[jsonable(transparent)]`
+///
+/// The only field is read, written, and validated as if it were the struct itself, so validation
+/// errors are the field's own.
+fn implement_transparent(
+    identifier: &Ident,
+    generics: &Generics,
+    into_json: bool,
+    input: FieldsUnnamed,
+) -> Result<TokenStream, String> {
+    if input.unnamed.len() != 1 {
+        return Err(format!(
+            "`transparent` requires exactly one field on {}",
+            identifier
+        ));
+    }
+
+    let field = input.unnamed.into_iter().next().unwrap();
+    let ty = field.ty;
+    let attributes = FieldAttributes::parse(&field.attrs)?;
+    attributes.expect_plain(&format!("tuple struct {}", identifier))?;
+    let codec = attributes.codec(&ty);
+    let validate = attributes.validate(&ty, quote! { json });
+    let fused = attributes.fused(&ty, quote! { json });
+    let owned = attributes.owned_json(&ty, quote! { self.0 });
+    let into_json = into_json.then(|| {
+        quote! {
+            fn into_json(self) -> serde_json::Value {
+                #owned
+            }
+        }
+    });
+    let schema = attributes.json_schema(&ty);
+
+    let generics = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let schema_ref_name = implement_schema_ref_name(identifier, &generics);
+    Ok(quote! {
+        impl #impl_generics jsonable::Jsonable for #identifier #ty_generics #where_clause {
+            #schema_ref_name
+
+            fn from_json_unchecked(json: serde_json::Value) -> Self {
+                Self(#codec::from_json_unchecked(json))
+            }
+
+            fn from_json_fused(json: serde_json::Value) -> jsonable::Result<Self> {
+                #fused.map(Self)
+            }
+
+            fn to_json(&self) -> serde_json::Value {
+                #codec::to_json(&self.0)
+            }
+
+            #into_json
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    #schema
+                })
+            }
+
+            fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
+                #validate
+            }
+        }
+    })
+}
+
+/// `#[jsonable(generate_patch)]`
+///
+/// Emits `<identifier>Patch` with every field wrapped in `Option`, along with its `Jsonable` impl
+/// and an `apply` method overlaying the present fields onto an `identifier`.
+/// Absent keys become `None`, as do `null` values of fields rejecting `null`. A field accepting
+/// `null` reads it as a value, so `null` clears an `Option` field through `Some(None)`.
+fn implement_patch(
+    identifier: &Ident,
+    visibility: &Visibility,
+    generics: &Generics,
+    rename_all: Option<RenameRule>,
+    input: &FieldsNamed,
+) -> Result<TokenStream, String> {
+    let patch_ident = format_ident!("{}Patch", identifier);
+    let patch_ident_str = patch_ident.to_string();
+    let doc = format!(
+        "Partial [{}] with every field optional, generated by `#[jsonable(generate_patch)]`",
+        identifier
+    );
+
+    let mut fields: Vec<TokenStream> = Vec::new();
+    let mut from_json_unchecked: Vec<TokenStream> = Vec::new();
+    let mut to_json: Vec<TokenStream> = Vec::new();
+    let mut validate_json: Vec<TokenStream> = Vec::new();
+    let mut json_schema: Vec<TokenStream> = Vec::new();
+    let mut apply: Vec<TokenStream> = Vec::new();
+
+    for field in input.named.iter() {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        if attributes.skip {
+            continue;
+        }
+        let ident_str = attributes.key(ident, rename_all);
+        attributes.expect_unflattened(&format!("patch {}", patch_ident))?;
+        let codec = attributes.codec(ty);
+        let validate = attributes.validate(ty, quote! { value });
+        let get = attributes.get(quote! { map }, &ident_str);
+        let remove = attributes.remove(quote! { inner_json }, &ident_str);
+        let schema = attributes.json_schema(ty);
+        let skips_null = quote! { #codec::validate_json(&serde_json::Value::Null).is_err() };
+
+        fields.push(quote! {
+            #vis #ident: Option<#ty>,
+        });
+
+        from_json_unchecked.push(quote! {
+            #ident: match #remove {
+                None => None,
+                Some(serde_json::Value::Null) if #skips_null => None,
+                Some(value) => Some(#codec::from_json_unchecked(value)),
+            },
+        });
+
+        to_json.push(quote! {
+            if let Some(value) = &self.#ident {
+                map.insert(#ident_str.into(), #codec::to_json(value));
+            }
+        });
+
+        validate_json.push(quote! {
+            match #get {
+                None => (),
+                Some(serde_json::Value::Null) if #skips_null => (),
+                Some(value) => if let Err(err) = #validate {
+                    errors.push((Some(#ident_str), jsonable::JsonableError::InnerErrorForType { ty: std::any::type_name::<#ty>(), error: Box::from(err)}));
+                }
+            }
+        });
+
+        json_schema.push(quote! {
+            .property(#ident_str, if #skips_null { jsonable::schema::nullable(#schema) } else { #schema }, false)
+        });
+
+        apply.push(quote! {
+            if let Some(value) = self.#ident {
+                target.#ident = value;
+            }
+        });
+    }
+
+    let where_clause = &generics.where_clause;
+    let bounded = with_jsonable_bounds(generics);
+    let (impl_generics, ty_generics, bounded_where_clause) = bounded.split_for_impl();
+    let (plain_impl_generics, _, plain_where_clause) = generics.split_for_impl();
+
+    let schema_ref_name = implement_schema_ref_name(&patch_ident, generics);
+    let collect_errors = implement_collect_errors(&patch_ident_str);
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Default)]
+        #visibility struct #patch_ident #generics #where_clause {
+            #(#fields)*
+        }
+
+        impl #plain_impl_generics #patch_ident #ty_generics #plain_where_clause {
+            /// Overwrites each field of `target` that is set in the patch
+            pub fn apply(self, target: &mut #identifier #ty_generics) {
+                #(#apply)*
+            }
+        }
+
+        impl #impl_generics jsonable::Jsonable for #patch_ident #ty_generics #bounded_where_clause {
+            #schema_ref_name
+
+            fn from_json_unchecked(mut json: serde_json::Value) -> Self {
+                let mut inner_json = json
+                    .as_object_mut()
+                    .unwrap_or_else(|| panic!("Tried converting non-object json to {}", #patch_ident_str));
+                Self {
+                    #(#from_json_unchecked)*
+                }
+            }
+
+            fn to_json(&self) -> serde_json::Value {
+                let mut map = serde_json::Map::new();
+
+                #(#to_json)*
+
+                serde_json::Value::Object(map)
+            }
+
+            fn json_schema() -> serde_json::Value {
+                jsonable::schema::definition(Self::schema_ref_name(), || {
+                    jsonable::schema::ObjectSchema::new()
+                        #(#json_schema)*
+                        .build(false)
+                })
+            }
+
+            fn validate_json(json: &serde_json::Value) -> jsonable::Result<()> {
+                match json {
+                    serde_json::Value::Object(map) => {
+                        let mut errors: Vec<(Option<&'static str>, jsonable::JsonableError)> = Vec::new();
+
+                        #(#validate_json)*
+
+                        #collect_errors
+                    },
+                    serde_json::Value::Array(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "array", expected: "object" }),
+                    serde_json::Value::Bool(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "bool", expected: "object" }),
+                    serde_json::Value::Null => Err(jsonable::JsonableError::IncompatibleJsonType { got: "null", expected: "object" }),
+                    serde_json::Value::Number(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "number", expected: "object" }),
+                    serde_json::Value::String(_) => Err(jsonable::JsonableError::IncompatibleJsonType { got: "string", expected: "object" })
+                }
+            }
+        }
+    })
 }
@@ -0,0 +1,82 @@
+//! `bool` fields that also accept numbers and strings, selected with `#[jsonable(coerce_bool)]`.
+//!
+//! Accepted when reading:
+//! - `true` and `false`
+//! - the numbers `1` and `0`
+//! - the strings `"true"`, `"yes"`, `"false"`, and `"no"`, in any case
+//!
+//! Any other number or string, such as `2` or `"on"`, is rejected. The value is always written as
+//! a json `true` or `false`.
+
+use std::marker::PhantomData;
+
+use serde_json::{json, Value};
+
+use crate::{JsonableError, Result};
+
+const EXPECTED: &str = "true/false, 1/0, or yes/no";
+
+/// Codec for a `bool` field that coerces numbers and strings.
+pub struct CoerceBool<B>(PhantomData<B>);
+
+impl CoerceBool<bool> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not one of the accepted forms
+    pub fn from_json_unchecked(json: Value) -> bool {
+        coerce(&json).unwrap_or_else(|| panic!("Tried converting {} to bool", json))
+    }
+
+    pub fn to_json(value: &bool) -> Value {
+        Value::Bool(*value)
+    }
+
+    pub fn json_schema() -> Value {
+        json!({ "type": ["boolean", "integer", "string"] })
+    }
+
+    /// Returns Err([JsonableError::InvalidFormat]) for numbers other than `0` and `1` and for
+    /// strings outside the accepted set.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Bool(_) => Ok(()),
+            Value::Number(_) | Value::String(_) => match coerce(json) {
+                Some(_) => Ok(()),
+                None => Err(JsonableError::InvalidFormat {
+                    expected: EXPECTED,
+                    got: match json {
+                        Value::String(value) => value.clone(),
+                        other => other.to_string(),
+                    },
+                }),
+            },
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "bool",
+            }),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "bool",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "bool",
+            }),
+        }
+    }
+}
+
+fn coerce(json: &Value) -> Option<bool> {
+    match json {
+        Value::Bool(value) => Some(*value),
+        Value::Number(number) => match number.as_u64() {
+            Some(0) => Some(false),
+            Some(1) => Some(true),
+            _ => None,
+        },
+        Value::String(value) => match value.to_lowercase().as_str() {
+            "true" | "yes" => Some(true),
+            "false" | "no" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
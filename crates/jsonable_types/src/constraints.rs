@@ -0,0 +1,99 @@
+//! Extra validation applied to fields with `#[jsonable(...)]` constraint attributes.
+//!
+//! Each check runs after the field type's own `validate_json` succeeded.
+
+use serde_json::Value;
+
+use crate::{JsonableError, Result};
+
+/// `#[jsonable(items(min = .., max = .., unique))]`
+///
+/// Checks the array length against `min` and `max`, then that no two entries are equal.
+/// Entries are compared as json, so `1` and `1.0` are different entries.
+///
+/// Non-array json is left to the field type's own validation and passes.
+pub fn items(json: &Value, min: Option<usize>, max: Option<usize>, unique: bool) -> Result<()> {
+    let array = match json {
+        Value::Array(array) => array,
+        _ => return Ok(()),
+    };
+
+    if let Some(min) = min {
+        if array.len() < min {
+            return Err(JsonableError::CollectionTooShort {
+                got: array.len(),
+                min,
+            });
+        }
+    }
+
+    if let Some(max) = max {
+        if array.len() > max {
+            return Err(JsonableError::CollectionTooLong {
+                got: array.len(),
+                max,
+            });
+        }
+    }
+
+    if unique {
+        for (index, entry) in array.iter().enumerate() {
+            if array[..index].contains(entry) {
+                return Err(JsonableError::DuplicateElement { index });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `#[jsonable(required_keys = ["..", ..])]`
+///
+/// Checks that every key in `keys` is present. Other keys are allowed.
+///
+/// Non-object json is left to the field type's own validation and passes.
+pub fn required_keys(json: &Value, keys: &[&'static str]) -> Result<()> {
+    let map = match json {
+        Value::Object(map) => map,
+        _ => return Ok(()),
+    };
+
+    match keys.iter().find(|key| !map.contains_key(**key)) {
+        Some(key) => Err(JsonableError::MissingRequiredKey {
+            key: key.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// `#[jsonable(max_bytes = ..)]`
+///
+/// Measures the json by writing it out compactly, as `serde_json::to_string` would, and counting
+/// the bytes without keeping them. Whitespace added by pretty printing is not counted.
+pub fn max_bytes(json: &Value, max: usize) -> Result<()> {
+    let mut counter = ByteCounter(0);
+    serde_json::to_writer(&mut counter, json).expect("Counting bytes cannot fail");
+
+    if counter.0 > max {
+        Err(JsonableError::PayloadTooLarge {
+            got: counter.0,
+            max,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Writer discarding everything written to it, only keeping the number of bytes.
+struct ByteCounter(usize);
+
+impl std::io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//! `chrono` date and time types, stored as strings.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use serde_json::{json, Value};
+
+use crate::{Jsonable, JsonableError, Result};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// Types stored as the string written by `$format` and read back with `$parse`.
+macro_rules! datetime_impl {
+    ($ty: ty, $expected: literal, $parse: expr, $format: expr, $schema: expr) => {
+        impl Jsonable for $ty {
+            /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a string that parses
+            fn from_json_unchecked(json: Value) -> Self {
+                json.as_str()
+                    .and_then(|value| $parse(value).ok())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Tried converting invalid string json to {}",
+                            std::any::type_name::<$ty>()
+                        )
+                    })
+            }
+
+            fn to_json(&self) -> Value {
+                Value::String($format(self))
+            }
+
+            /// Returns Err([JsonableError::InvalidFormat]) if the string does not parse.
+            fn validate_json(json: &Value) -> Result<()> {
+                match json {
+                    Value::String(value) => match $parse(value) {
+                        Ok(_) => Ok(()),
+                        Err(_) => Err(JsonableError::InvalidFormat {
+                            expected: $expected,
+                            got: value.clone(),
+                        }),
+                    },
+                    Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "array",
+                        expected: "string",
+                    }),
+                    Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "bool",
+                        expected: "string",
+                    }),
+                    Value::Null => Err(JsonableError::IncompatibleJsonType {
+                        got: "null",
+                        expected: "string",
+                    }),
+                    Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "number",
+                        expected: "string",
+                    }),
+                    Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "object",
+                        expected: "string",
+                    }),
+                }
+            }
+
+            fn json_schema() -> Value {
+                $schema
+            }
+        }
+    };
+}
+
+// RFC 3339 with any offset is accepted, and converted to UTC. Written with a `Z` suffix, and
+// fractional seconds only when there are any.
+datetime_impl!(
+    DateTime<Utc>,
+    "RFC 3339 date and time",
+    |value: &str| DateTime::parse_from_rfc3339(value).map(|time| time.with_timezone(&Utc)),
+    |time: &DateTime<Utc>| time.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+    json!({ "type": "string", "format": "date-time" })
+);
+// `2024-01-31`
+datetime_impl!(
+    NaiveDate,
+    "ISO 8601 date",
+    |value: &str| NaiveDate::parse_from_str(value, DATE_FORMAT),
+    |date: &NaiveDate| date.format(DATE_FORMAT).to_string(),
+    json!({ "type": "string", "format": "date" })
+);
+// `2024-01-31T12:30:00`, without an offset
+datetime_impl!(
+    NaiveDateTime,
+    "ISO 8601 date and time",
+    |value: &str| NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT),
+    |time: &NaiveDateTime| time.format(DATE_TIME_FORMAT).to_string(),
+    json!({ "type": "string" })
+);
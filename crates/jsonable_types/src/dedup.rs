@@ -0,0 +1,48 @@
+//! `Vec` fields that drop repeated entries, selected with `#[jsonable(dedup)]`.
+//!
+//! Only the first occurrence of each entry is kept, in the order they appear, so `[2, 1, 2, 3, 1]`
+//! reads as `[2, 1, 3]`. This is lossy: the repeats are silently discarded rather than reported,
+//! use `#[jsonable(items(unique))]` to reject them instead.
+//!
+//! Entries are compared by their json text, so `1` and `1.0` are different entries. With the
+//! `indexmap` feature objects keep their key order, so the same keys in another order also make
+//! a different entry.
+
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+use serde_json::Value;
+
+use crate::{schema, Jsonable, Result};
+
+/// Codec for a `Vec<T>` field that removes duplicate entries when read.
+pub struct Dedup<V>(PhantomData<V>);
+
+impl<T: Jsonable> Dedup<Vec<T>> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an array
+    pub fn from_json_unchecked(json: Value) -> Vec<T> {
+        match json {
+            Value::Array(entries) => {
+                let mut seen = HashSet::with_capacity(entries.len());
+                let unique = entries
+                    .into_iter()
+                    .filter(|entry| seen.insert(entry.to_string()))
+                    .collect();
+                Vec::<T>::from_json_unchecked(Value::Array(unique))
+            }
+            json => Vec::<T>::from_json_unchecked(json),
+        }
+    }
+
+    pub fn to_json(value: &Vec<T>) -> Value {
+        value.to_json()
+    }
+
+    pub fn json_schema() -> Value {
+        schema::array(T::json_schema())
+    }
+
+    pub fn validate_json(json: &Value) -> Result<()> {
+        Vec::<T>::validate_json(json)
+    }
+}
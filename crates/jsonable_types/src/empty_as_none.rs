@@ -0,0 +1,54 @@
+//! `Option` fields that read an empty object or array as `None`, selected with `#[jsonable(empty_as_none)]`.
+//!
+//! `null`, `{}`, and `[]` all read as `None`, anything else is read as `Some(T)`. `None` is
+//! still written as `null`.
+//!
+//! When `T` is itself a collection, such as `Option<Vec<u8>>`, an empty collection can no longer
+//! be told apart from a missing one and reads as `None` rather than `Some(vec![])`.
+
+use std::marker::PhantomData;
+
+use serde_json::{json, Value};
+
+use crate::{schema, Jsonable, Result};
+
+/// Codec for an `Option<T>` field that treats empty objects and arrays as `None`.
+pub struct EmptyAsNone<O>(PhantomData<O>);
+
+impl<T: Jsonable> EmptyAsNone<Option<T>> {
+    pub fn from_json_unchecked(json: Value) -> Option<T> {
+        if is_empty(&json) {
+            None
+        } else {
+            Option::<T>::from_json_unchecked(json)
+        }
+    }
+
+    pub fn to_json(value: &Option<T>) -> Value {
+        value.to_json()
+    }
+
+    pub fn json_schema() -> Value {
+        schema::any_of(vec![
+            Option::<T>::json_schema(),
+            json!({ "type": "object", "maxProperties": 0 }),
+            json!({ "type": "array", "maxItems": 0 }),
+        ])
+    }
+
+    pub fn validate_json(json: &Value) -> Result<()> {
+        if is_empty(json) {
+            Ok(())
+        } else {
+            Option::<T>::validate_json(json)
+        }
+    }
+}
+
+fn is_empty(json: &Value) -> bool {
+    match json {
+        Value::Object(map) => map.is_empty(),
+        Value::Array(vec) => vec.is_empty(),
+        _ => false,
+    }
+}
@@ -0,0 +1,60 @@
+//! Struct fields whose keys are merged into the parent object, selected with
+//! `#[jsonable(flatten)]` or `#[jsonable(flatten, prefix = "..")]`.
+//!
+//! With a prefix, every key of the field is written as `<prefix><key>`, and only the parent's keys
+//! starting with the prefix are read back, with the prefix stripped. Without one, the field sees
+//! every key of the parent that no other field claimed.
+//!
+//! The field's type must be written as a json object. Reading anything else fails validation, as
+//! the field is always given an object, and writing anything else panics.
+
+use serde_json::{Map, Value};
+
+/// Moves the keys starting with `prefix` out of `map` into an object for the field, stripping the prefix.
+///
+/// Without a prefix the keys are copied rather than moved, so several unprefixed fields each see them.
+pub fn take(map: &mut Map<String, Value>, prefix: &str) -> Value {
+    if prefix.is_empty() {
+        return Value::Object(map.clone());
+    }
+
+    let keys: Vec<String> = map
+        .keys()
+        .filter(|key| key.starts_with(prefix))
+        .cloned()
+        .collect();
+
+    let mut inner = Map::with_capacity(keys.len());
+    for key in keys {
+        let value = map.remove(&key).unwrap();
+        inner.insert(key[prefix.len()..].to_string(), value);
+    }
+
+    Value::Object(inner)
+}
+
+/// Copies the keys starting with `prefix` out of `map` into an object for the field, stripping the prefix.
+pub fn select(map: &Map<String, Value>, prefix: &str) -> Value {
+    Value::Object(
+        map.iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix(prefix)
+                    .map(|key| (key.to_string(), value.clone()))
+            })
+            .collect(),
+    )
+}
+
+/// Inserts every key of the field's object into `map` with `prefix` prepended.
+///
+/// Panics if the field was not written as an object, since its keys cannot be merged into `map`.
+pub fn insert(map: &mut Map<String, Value>, prefix: &str, json: Value) {
+    match json {
+        Value::Object(inner) => {
+            for (key, value) in inner {
+                map.insert(format!("{}{}", prefix, key), value);
+            }
+        }
+        _ => panic!("Tried flattening non-object json into the parent object"),
+    }
+}
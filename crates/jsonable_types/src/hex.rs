@@ -0,0 +1,122 @@
+//! Byte fields stored as hex strings, selected with `#[jsonable(as_hex)]`.
+//!
+//! `vec![0xde, 0xad]` is written as `"dead"`. Upper and lower case digits are both accepted when
+//! reading, but only lower case is written. Works for `Vec<u8>` and `[u8; N]` fields; the string
+//! for a fixed array must hold exactly `N` bytes.
+
+use std::marker::PhantomData;
+
+use serde_json::{json, Value};
+
+use crate::{JsonableError, Result};
+
+const EXPECTED: &str = "hex string";
+
+/// Codec for a byte field stored as a hex string.
+pub struct Hex<B>(PhantomData<B>);
+
+impl Hex<Vec<u8>> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a hex string
+    pub fn from_json_unchecked(json: Value) -> Vec<u8> {
+        let value = json
+            .as_str()
+            .unwrap_or_else(|| panic!("Tried converting non-string json to hex bytes"));
+        decode(value).unwrap_or_else(|| panic!("Invalid hex string: {}", value))
+    }
+
+    pub fn to_json(value: &[u8]) -> Value {
+        Value::String(encode(value))
+    }
+
+    pub fn json_schema() -> Value {
+        json!({ "type": "string", "pattern": "^([0-9a-fA-F]{2})*$" })
+    }
+
+    /// Returns Err([JsonableError::InvalidFormat]) if the string has an odd length or a non-hex digit.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        validate(json).map(|_| ())
+    }
+}
+
+impl<const N: usize> Hex<[u8; N]> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a hex string of `N` bytes
+    pub fn from_json_unchecked(json: Value) -> [u8; N] {
+        Hex::<Vec<u8>>::from_json_unchecked(json)
+            .try_into()
+            .unwrap_or_else(|bytes: Vec<u8>| {
+                panic!("Expected {} hex bytes. Got {} instead", N, bytes.len())
+            })
+    }
+
+    pub fn to_json(value: &[u8; N]) -> Value {
+        Value::String(encode(value))
+    }
+
+    pub fn json_schema() -> Value {
+        json!({ "type": "string", "pattern": format!("^[0-9a-fA-F]{{{}}}$", N * 2) })
+    }
+
+    /// Returns Err([JsonableError::InvalidArrayLength]) if the string does not hold `N` bytes.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match validate(json)? {
+            len if len == N => Ok(()),
+            len => Err(JsonableError::InvalidArrayLength {
+                got: len,
+                expected: N,
+            }),
+        }
+    }
+}
+
+/// Number of bytes held by the hex string in `json`.
+fn validate(json: &Value) -> Result<usize> {
+    match json {
+        Value::String(value) => match decode(value) {
+            Some(bytes) => Ok(bytes.len()),
+            None => Err(JsonableError::InvalidFormat {
+                expected: EXPECTED,
+                got: value.clone(),
+            }),
+        },
+        Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "array",
+            expected: "string",
+        }),
+        Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "bool",
+            expected: "string",
+        }),
+        Value::Null => Err(JsonableError::IncompatibleJsonType {
+            got: "null",
+            expected: "string",
+        }),
+        Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "number",
+            expected: "string",
+        }),
+        Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "object",
+            expected: "string",
+        }),
+    }
+}
+
+fn encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+
+    value
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
@@ -0,0 +1,101 @@
+//! `indexmap` collections, which keep their entries in the order of the json.
+//!
+//! The `indexmap` feature also enables `serde_json`'s `preserve_order`, without which json objects
+//! sort their keys before an `IndexMap` ever sees them.
+
+use std::hash::Hash;
+
+use indexmap::{IndexMap, IndexSet};
+use serde_json::Value;
+
+use crate::map::{map_from_json_fused, validate_map, validate_map_all};
+use crate::{schema, JsonMapKey, Jsonable, JsonableError, Result};
+
+/// Keys are read in the order of the json object, and written in insertion order.
+impl<K, T> Jsonable for IndexMap<K, T>
+where
+    K: JsonMapKey + Hash + Eq,
+    T: Jsonable,
+{
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an object
+    fn from_json_unchecked(json: Value) -> Self {
+        match json {
+            Value::Object(obj) => obj
+                .into_iter()
+                .map(|(key, value)| (K::from_key(key), T::from_json_unchecked(value)))
+                .collect(),
+            _ => panic!("Tried converting non-object json to IndexMap"),
+        }
+    }
+
+    fn from_json_fused(json: Value) -> Result<Self> {
+        map_from_json_fused(json)
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Object(
+            self.iter()
+                .map(|(key, value)| (key.to_key(), value.to_json()))
+                .collect(),
+        )
+    }
+
+    fn into_json(self) -> Value {
+        Value::Object(
+            self.into_iter()
+                .map(|(key, value)| (key.into_key(), value.into_json()))
+                .collect(),
+        )
+    }
+
+    /// Returns Err([JsonableError::InvalidMapValue]) with the key of the first value that cannot be converted to T.
+    ///
+    /// Returns Err([JsonableError::IncompatibleJsonType]) if the json value is not an object.
+    fn validate_json(json: &Value) -> Result<()> {
+        validate_map::<T>(json)
+    }
+
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        validate_map_all::<T>(json)
+    }
+
+    fn json_schema() -> Value {
+        schema::definition(None, || schema::map(T::json_schema()))
+    }
+}
+
+/// Stored as an array in insertion order. Repeated entries are read once, at their first position.
+impl<T> Jsonable for IndexSet<T>
+where
+    T: Jsonable + Hash + Eq,
+{
+    fn from_json_unchecked(json: Value) -> Self {
+        Vec::<T>::from_json_unchecked(json).into_iter().collect()
+    }
+
+    fn from_json_fused(json: Value) -> Result<Self> {
+        Vec::<T>::from_json_fused(json).map(|vec| vec.into_iter().collect())
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(|entry| entry.to_json()).collect())
+    }
+
+    fn into_json(self) -> Value {
+        Value::Array(self.into_iter().map(T::into_json).collect())
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        Vec::<T>::validate_json(json)
+    }
+
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        Vec::<T>::validate_json_all(json)
+    }
+
+    fn json_schema() -> Value {
+        let mut schema = schema::definition(None, || schema::array(T::json_schema()));
+        schema["uniqueItems"] = true.into();
+        schema
+    }
+}
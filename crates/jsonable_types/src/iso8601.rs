@@ -0,0 +1,154 @@
+//! [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) representations, selected on fields with `#[jsonable(...)]` attributes.
+
+/// [Duration](std::time::Duration) as an ISO 8601 duration string such as `"PT1H30M"`.
+///
+/// Selected with `#[jsonable(duration = "iso8601")]`.
+///
+/// Weeks, days, hours, minutes, and (fractional) seconds are accepted. Years and months are
+/// rejected since they do not have a fixed length. Written durations only use hours, minutes,
+/// and seconds.
+pub mod duration {
+    use std::time::Duration;
+
+    use serde_json::{json, Value};
+
+    use crate::{JsonableError, Result};
+
+    const EXPECTED: &str = "ISO 8601 duration";
+
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a valid ISO 8601 duration string
+    pub fn from_json_unchecked(json: Value) -> Duration {
+        let value = json
+            .as_str()
+            .unwrap_or_else(|| panic!("Tried converting non-string json to Duration"));
+        parse(value).unwrap_or_else(|| panic!("Invalid ISO 8601 duration: {}", value))
+    }
+
+    pub fn to_json(value: &Duration) -> Value {
+        let secs = value.as_secs();
+        let nanos = value.subsec_nanos();
+        let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+        let mut result = String::from("PT");
+        if hours > 0 {
+            result.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            result.push_str(&format!("{}M", minutes));
+        }
+        if nanos > 0 {
+            let fraction = format!("{:09}", nanos);
+            result.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+        } else if seconds > 0 || result.len() == 2 {
+            result.push_str(&format!("{}S", seconds));
+        }
+
+        Value::String(result)
+    }
+
+    pub fn json_schema() -> Value {
+        json!({ "type": "string", "format": "duration" })
+    }
+
+    /// Returns Err([JsonableError::InvalidFormat]) if the string is not a valid ISO 8601 duration.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::String(value) => match parse(value) {
+                Some(_) => Ok(()),
+                None => Err(JsonableError::InvalidFormat {
+                    expected: EXPECTED,
+                    got: value.clone(),
+                }),
+            },
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "string",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "string",
+            }),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "string",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "string",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "string",
+            }),
+        }
+    }
+
+    fn parse(value: &str) -> Option<Duration> {
+        let mut rest = value.strip_prefix('P')?;
+        let mut total = Duration::ZERO;
+        let mut in_time = false;
+        let mut last_unit = u64::MAX;
+
+        while !rest.is_empty() {
+            if !in_time {
+                if let Some(time) = rest.strip_prefix('T') {
+                    if time.is_empty() {
+                        return None;
+                    }
+                    in_time = true;
+                    rest = time;
+                    continue;
+                }
+            }
+
+            let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')?;
+            let (number, designator) = (&rest[..end], rest[end..].chars().next()?);
+            rest = &rest[end + designator.len_utf8()..];
+
+            let (whole, fraction) = match number.find(['.', ',']) {
+                Some(idx) => (&number[..idx], Some(&number[idx + 1..])),
+                None => (number, None),
+            };
+            if whole.is_empty() {
+                return None;
+            }
+            let whole: u64 = whole.parse().ok()?;
+
+            let unit = match (in_time, designator) {
+                (false, 'W') => 604_800,
+                (false, 'D') => 86_400,
+                (true, 'H') => 3600,
+                (true, 'M') => 60,
+                (true, 'S') => 1,
+                _ => return None,
+            };
+            // Components must appear at most once, from largest to smallest
+            if unit >= last_unit {
+                return None;
+            }
+            last_unit = unit;
+
+            total = total.checked_add(Duration::from_secs(whole.checked_mul(unit)?))?;
+
+            if let Some(fraction) = fraction {
+                // Only the seconds component may be fractional, and it must be the last one
+                if designator != 'S'
+                    || !rest.is_empty()
+                    || fraction.is_empty()
+                    || fraction.len() > 9
+                    || !fraction.chars().all(|c| c.is_ascii_digit())
+                {
+                    return None;
+                }
+                let nanos: u32 = format!("{:0<9}", fraction).parse().ok()?;
+                total = total.checked_add(Duration::from_nanos(nanos.into()))?;
+            }
+        }
+
+        if last_unit == u64::MAX {
+            None
+        } else {
+            Some(total)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde_json::Value;
+
+use crate::{Jsonable, JsonableError};
+
+/// Wraps a [Jsonable] type to convert it with the standard conversion traits.
+///
+/// A blanket `impl<T: Jsonable> TryFrom<Value> for T` would conflict with the one core provides
+/// for every type, so the conversions are implemented for the wrapper instead:
+///
+/// ```ignore
+/// let Json(person) = Json::<Person>::try_from(value)?;
+/// let value: Value = Json(person).into();
+/// ```
+///
+/// Reading goes through [Jsonable::from_json], so invalid json is returned as a [JsonableError].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Json<T>(pub T);
+
+impl<T: Jsonable> TryFrom<Value> for Json<T> {
+    type Error = JsonableError;
+
+    fn try_from(json: Value) -> Result<Self, Self::Error> {
+        T::from_json(json).map(Json)
+    }
+}
+
+impl<T: Jsonable> From<Json<T>> for Value {
+    fn from(json: Json<T>) -> Self {
+        json.0.to_json()
+    }
+}
@@ -1,16 +1,98 @@
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet, VecDeque};
 use std::hash::Hash;
-
-use serde_json::{self, Map, Value};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json::{self, json, Map, Number, Value};
+
+pub mod coerce_bool;
+pub mod constraints;
+#[cfg(feature = "chrono")]
+mod datetime;
+pub mod dedup;
+pub mod empty_as_none;
+pub mod flatten;
+pub mod hex;
+#[cfg(feature = "indexmap")]
+mod index_map;
+#[cfg(feature = "iso8601")]
+pub mod iso8601;
+mod json_wrapper;
+pub mod lowercase_keys;
+mod map;
+mod map_key;
+mod non_finite;
+pub mod null_as_empty;
+pub mod null_as_zero;
+pub mod object_as_vec;
+mod raw_number;
+pub mod required;
+pub mod rfc3339;
+pub mod schema;
+mod schema_map;
+#[cfg(feature = "serde")]
+pub mod with_serde;
+
+pub use json_wrapper::Json;
+pub use map_key::JsonMapKey;
+pub use raw_number::RawNumber;
+pub use schema_map::SchemaMap;
 
 /// Error enum returned from [Jsonable::from_json] or [Jsonable::validate_json]
 ///
 /// `IncompatibleJsonType` - json cannot be converted to the current type
 ///
-/// `IncompatibleEntryForType` - json array contains a value that cannot be converted to the current type
+/// `IncompatibleEntryForType` - json array contains a value that cannot be converted to the current type. The provided
+/// impls report `IncompatibleEntryAt` instead, this is kept for existing matches
 ///
 /// `InnerErrorForType` - at least one json object's value cannot be converted to its type
 ///
+/// `InnerErrorsForType` - several values cannot be converted. A derived struct reports each of its fields as `InvalidMapValue`
+/// under the field's key
+///
+/// `InvalidFormat` - json string is not in the format expected by the type
+///
+/// `IncompatibleEntryAt` - json array's entry at `index` cannot be converted to the current type
+///
+/// `InvalidMapValue` - json object's value under `key` cannot be converted to the map's value type
+///
+/// `MissingEnumTag` - json object for an internally or adjacently tagged enum has no `tag` key, or nothing is found at an enum's `tag_path`
+///
+/// `CollectionTooShort`, `CollectionTooLong`, `DuplicateElement` - json array breaks a `#[jsonable(items(...))]` constraint
+///
+/// `MissingRequiredKey` - json object is missing a key listed in `#[jsonable(required_keys = [...])]`, a key of a [SchemaMap], or the `secs`/`nanos` of a `Duration`
+///
+/// `NumberOutOfRange` - json number does not fit in the integer type, or `nanos` of a `Duration` is a second or more
+///
+/// `ZeroForNonZero` - json number is `0` for a `NonZero*` type
+///
+/// `DuplicateKey` - json object has keys that only differ by case for a `#[jsonable(lowercase_keys)]` map
+///
+/// `VariantValidation` - json object selected `variant` of an enum, by its tag or its key, but its fields cannot be converted
+///
+/// `MissingField` - json object has no value, or `null`, for a `#[jsonable(required)]` field
+///
+/// `PayloadTooLarge` - json takes more bytes than allowed by `#[jsonable(max_bytes = ..)]`
+///
+/// `UnknownField` - json object has a key matching no field of a `#[jsonable(deny_unknown_fields)]` struct or enum variant
+///
+/// `NoUntaggedVariantMatched` - json fits none of the variants of a `#[jsonable(untagged)]` enum
+///
+/// `InvalidEnumDiscriminant` - json number is not the discriminant of any variant of a `#[jsonable(numeric)]` enum
+///
+/// `Parse` - text passed to [Jsonable::from_json_str] is not valid json
+///
+/// `Serde` - json cannot be deserialized by the serde impl of a `#[jsonable(with_serde)]` field
+///
 /// ## Examples
 /// ```ignore
 /// use serde_json::{Result, Value};
@@ -95,6 +177,10 @@ pub enum JsonableError {
         got: usize,
         expected: usize,
     },
+    InvalidObjectLength {
+        got: usize,
+        expected: usize,
+    },
     InvalidEnumStringVariant {
         enum_type: &'static str,
         got: String,
@@ -112,11 +198,208 @@ pub enum JsonableError {
     IncorrectKeyForEnum {
         ty: &'static str,
         key: String,
+        expected: Vec<&'static str>,
     },
     MissingKeyForEnumVariant {
         variant: &'static str,
         key: &'static str,
     },
+    InvalidFormat {
+        expected: &'static str,
+        got: String,
+    },
+    IncompatibleEntryAt {
+        index: usize,
+        error: Box<JsonableError>,
+    },
+    InvalidMapValue {
+        key: String,
+        error: Box<JsonableError>,
+    },
+    MissingEnumTag {
+        ty: &'static str,
+        tag: &'static str,
+    },
+    CollectionTooShort {
+        got: usize,
+        min: usize,
+    },
+    CollectionTooLong {
+        got: usize,
+        max: usize,
+    },
+    DuplicateElement {
+        index: usize,
+    },
+    MissingRequiredKey {
+        key: String,
+    },
+    NumberOutOfRange {
+        ty: &'static str,
+        value: Number,
+    },
+    ZeroForNonZero {
+        ty: &'static str,
+    },
+    DuplicateKey {
+        key: String,
+    },
+    VariantValidation {
+        variant: &'static str,
+        error: Box<JsonableError>,
+    },
+    MissingField,
+    PayloadTooLarge {
+        got: usize,
+        max: usize,
+    },
+    UnknownField {
+        ty: &'static str,
+        field: String,
+    },
+    NoUntaggedVariantMatched {
+        ty: &'static str,
+    },
+    InvalidEnumDiscriminant {
+        enum_type: &'static str,
+        got: Number,
+        expected: Vec<i64>,
+    },
+    Parse(String),
+    Serde(String),
+}
+
+/// Renders the error as a sentence. Nested errors are chained with `->`, from the outermost type
+/// down to the value that failed, such as `Person -> u8 -> expected a JSON number but got string`.
+impl std::fmt::Display for JsonableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IncompatibleJsonType { got, expected } => {
+                write!(f, "expected a JSON {} but got {}", expected, got)
+            }
+            Self::IncompatibleEntryForType(ty) => {
+                write!(f, "array entry cannot be converted to {}", ty)
+            }
+            Self::InnerErrorForType { ty, error } => write!(f, "{} -> {}", ty, error),
+            Self::InnerErrorsForType { ty, errors } => {
+                write!(f, "{} -> (", ty)?;
+                for (index, error) in errors.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", error)?;
+                }
+                write!(f, ")")
+            }
+            Self::InvalidArrayLength { got, expected } => {
+                write!(f, "expected {} array entries but got {}", expected, got)
+            }
+            Self::InvalidObjectLength { got, expected } => {
+                write!(f, "expected {} object keys but got {}", expected, got)
+            }
+            Self::InvalidEnumStringVariant {
+                enum_type,
+                got,
+                expected,
+            } => write!(
+                f,
+                "unknown variant `{}` of {}, expected one of: {}",
+                got,
+                enum_type,
+                expected.join(", ")
+            ),
+            Self::IncorrectObjectKeyCountForEnum { ty, count } => write!(
+                f,
+                "expected a single key object for {} but got {} keys",
+                ty, count
+            ),
+            Self::IncorrectFieldCountForEnum {
+                enum_type,
+                variant,
+                count,
+            } => write!(
+                f,
+                "expected {} fields for variant {}::{}",
+                count, enum_type, variant
+            ),
+            Self::IncorrectKeyForEnum { ty, key, expected } => write!(
+                f,
+                "unknown variant `{}` of {}, expected one of: {}",
+                key,
+                ty,
+                expected.join(", ")
+            ),
+            Self::MissingKeyForEnumVariant { variant, key } => {
+                write!(f, "missing key `{}` for variant {}", key, variant)
+            }
+            Self::InvalidFormat { expected, got } => {
+                write!(f, "expected a {} but got `{}`", expected, got)
+            }
+            Self::IncompatibleEntryAt { index, error } => write!(f, "[{}] -> {}", index, error),
+            Self::InvalidMapValue { key, error } => write!(f, "`{}` -> {}", key, error),
+            Self::MissingEnumTag { ty, tag } => write!(f, "missing tag `{}` for {}", tag, ty),
+            Self::CollectionTooShort { got, min } => {
+                write!(f, "expected at least {} entries but got {}", min, got)
+            }
+            Self::CollectionTooLong { got, max } => {
+                write!(f, "expected at most {} entries but got {}", max, got)
+            }
+            Self::DuplicateElement { index } => {
+                write!(f, "entry {} duplicates an earlier entry", index)
+            }
+            Self::MissingRequiredKey { key } => write!(f, "missing required key `{}`", key),
+            Self::NumberOutOfRange { ty, value } => {
+                write!(f, "{} is out of range for {}", value, ty)
+            }
+            Self::ZeroForNonZero { ty } => write!(f, "expected a non-zero number for {}", ty),
+            Self::DuplicateKey { key } => {
+                write!(f, "key `{}` appears more than once when ignoring case", key)
+            }
+            Self::VariantValidation { variant, error } => {
+                write!(f, "variant `{}` -> {}", variant, error)
+            }
+            Self::MissingField => write!(f, "missing required field"),
+            Self::PayloadTooLarge { got, max } => {
+                write!(f, "expected at most {} bytes but got {}", max, got)
+            }
+            Self::UnknownField { ty, field } => write!(f, "unknown field `{}` for {}", field, ty),
+            Self::NoUntaggedVariantMatched { ty } => write!(f, "json matches no variant of {}", ty),
+            Self::InvalidEnumDiscriminant {
+                enum_type,
+                got,
+                expected,
+            } => write!(
+                f,
+                "unknown discriminant {} of {}, expected one of: {}",
+                got,
+                enum_type,
+                expected
+                    .iter()
+                    .map(i64::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Parse(reason) => write!(f, "invalid json: {}", reason),
+            Self::Serde(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// The source of a nested error is the error it wraps. Only the first error of
+/// `InnerErrorsForType` is exposed, since a source is a single error.
+impl std::error::Error for JsonableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InnerErrorForType { error, .. }
+            | Self::IncompatibleEntryAt { error, .. }
+            | Self::InvalidMapValue { error, .. }
+            | Self::VariantValidation { error, .. } => Some(error.as_ref()),
+            Self::InnerErrorsForType { errors, .. } => errors
+                .first()
+                .map(|error| error as &(dyn std::error::Error + 'static)),
+            _ => None,
+        }
+    }
 }
 
 /// Return type for [Jsonable::from_json] and [Jsonable::validate_json]
@@ -133,49 +416,206 @@ pub trait Jsonable: Sized {
         }
     }
 
+    /// Consumes the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) and returns the resulting value, validating it in the same pass.
+    /// Returns the same error as [Jsonable::from_json], without walking the json twice.
+    /// Provides a default implementation that falls back to [Jsonable::from_json].
+    fn from_json_fused(json: Value) -> Result<Self> {
+        Self::from_json(json)
+    }
+
+    /// Parses `text` as json and converts it with [Jsonable::from_json].
+    /// Returns Err([JsonableError::Parse]) if `text` is not valid json.
+    fn from_json_str(text: &str) -> Result<Self> {
+        let json =
+            serde_json::from_str(text).map_err(|err| JsonableError::Parse(err.to_string()))?;
+        Self::from_json(json)
+    }
+
+    /// Parses json text read from `reader` and converts it with [Jsonable::from_json].
+    /// Returns Err([JsonableError::Parse]) if reading fails or the text is not valid json.
+    fn from_reader<R: std::io::Read>(reader: R) -> Result<Self> {
+        let json =
+            serde_json::from_reader(reader).map_err(|err| JsonableError::Parse(err.to_string()))?;
+        Self::from_json(json)
+    }
+
     /// Consumes the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) and returns the resulting value.
     /// Provided implementations panic if conversion failed.
     fn from_json_unchecked(json: Value) -> Self;
 
+    /// Applies `patch` onto the object: objects are merged key by key, anything else, including `null`,
+    /// replaces the value it lands on. The object is left unchanged if the merged json fails validation.
+    /// Provides a default implementation that merges into [Jsonable::to_json] and converts back with [Jsonable::from_json].
+    fn merge_json(&mut self, patch: Value) -> Result<()> {
+        let mut json = self.to_json();
+        merge(&mut json, patch);
+        *self = Self::from_json(json)?;
+        Ok(())
+    }
+
+    /// [JSON Patch](https://datatracker.ietf.org/doc/html/rfc6902) turning the json of the object into the json of `other`.
+    /// Identical objects produce an empty array.
+    #[cfg(feature = "diff")]
+    fn diff(&self, other: &Self) -> Value {
+        let patch = json_patch::diff(&self.to_json(), &other.to_json());
+        serde_json::to_value(patch).expect("a json patch always serializes")
+    }
+
     /// Converts the object into a [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html).
     fn to_json(&self) -> Value;
 
+    /// Consumes the object and converts it into a [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html),
+    /// moving strings and collections out instead of cloning them.
+    /// Provides a default implementation that falls back to [Jsonable::to_json].
+    fn into_json(self) -> Value {
+        self.to_json()
+    }
+
+    /// Writes the object as json text into `writer`.
+    /// Provides a default implementation that writes the result of [Jsonable::to_json].
+    fn to_json_writer<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        serde_json::to_writer(writer, &self.to_json()).map_err(std::io::Error::from)
+    }
+
+    /// Writes the object as json text into `writer`, taking it by value like [serde_json::to_writer].
+    /// Provides a default implementation that calls [Jsonable::to_json_writer].
+    fn to_writer<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        self.to_json_writer(&mut writer)
+    }
+
+    /// Converts the object into compact json text.
+    fn to_json_string(&self) -> String {
+        self.to_json().to_string()
+    }
+
+    /// Converts the object into indented json text.
+    fn to_json_string_pretty(&self) -> String {
+        format!("{:#}", self.to_json())
+    }
+
     /// Validates that the provided [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) can be converted to the type.
     fn validate_json(json: &Value) -> Result<()>;
+
+    /// Validates the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html), collecting every error instead of stopping at the first one.
+    /// An empty list means the json can be converted.
+    /// Provides a default implementation returning the error from [Jsonable::validate_json], if any.
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        Self::validate_json(json).err().into_iter().collect()
+    }
+
+    /// Stable name for referring to the type's schema, such as `"Foo"` in `$ref: "#/$defs/Foo"`.
+    /// Derived structs and enums return their identifier, so nested types are referenced instead of inlined,
+    /// unless they have type or const parameters, since `Pair<u8>` and `Pair<String>` cannot share a name.
+    /// Provides a default implementation returning `None`, for types that are always inlined.
+    fn schema_ref_name() -> Option<&'static str> {
+        None
+    }
+
+    /// [JSON Schema](https://json-schema.org) describing the json accepted by [Jsonable::validate_json].
+    /// Derived structs and enums describe their fields and variants. Nested types with a [Jsonable::schema_ref_name]
+    /// are referenced, their schemas collected under the outermost schema's `$defs`.
+    /// Provides a default implementation returning the empty schema, which accepts any json.
+    fn json_schema() -> Value {
+        Value::Object(Map::new())
+    }
+}
+
+/// Merges `patch` into `target` for [Jsonable::merge_json].
+fn merge(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                match target.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
+
+/// Inserts `value` into `map` under `key` only when it is `Some`, leaving absent keys for `None`.
+///
+/// Saves repeating `if let Some(..)` for optional fields in hand-written [Jsonable::to_json] impls.
+pub fn insert_if_present<T: Jsonable>(map: &mut Map<String, Value>, key: &str, value: &Option<T>) {
+    if let Some(value) = value {
+        map.insert(key.into(), value.to_json());
+    }
 }
 
 impl<T: Jsonable> Jsonable for Vec<T> {
     /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an [Array](https://docs.serde.rs/serde_json/value/enum.Value.html#variant.Array)
-    fn from_json_unchecked(mut json: Value) -> Self {
-        json.as_array_mut()
-            .unwrap_or_else(|| panic!("Tried converting non-array json to Vec"))
-            .to_owned()
-            .into_iter()
-            .map(|value| T::from_json_unchecked(value))
-            .collect::<Self>()
+    ///
+    /// The result is allocated once, sized to the array.
+    fn from_json_unchecked(json: Value) -> Self {
+        let entries = match json {
+            Value::Array(entries) => entries,
+            _ => panic!("Tried converting non-array json to Vec"),
+        };
+
+        let mut result = Vec::with_capacity(entries.len());
+        for entry in entries {
+            result.push(T::from_json_unchecked(entry));
+        }
+        result
+    }
+
+    fn from_json_fused(json: Value) -> Result<Self> {
+        let entries = match json {
+            Value::Array(entries) => entries,
+            json => return Self::from_json(json),
+        };
+
+        let mut result = Vec::with_capacity(entries.len());
+        for (index, entry) in entries.into_iter().enumerate() {
+            let entry =
+                T::from_json_fused(entry).map_err(|err| JsonableError::IncompatibleEntryAt {
+                    index,
+                    error: Box::from(err),
+                })?;
+            result.push(entry);
+        }
+        Ok(result)
     }
 
     fn to_json(&self) -> Value {
-        Value::Array(self.into_iter().map(|entry| entry.to_json()).collect())
+        Value::Array(self.iter().map(|entry| entry.to_json()).collect())
+    }
+
+    fn into_json(self) -> Value {
+        Value::Array(self.into_iter().map(T::into_json).collect())
+    }
+
+    /// Streams each entry into `writer` without building the whole array as a [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html).
+    fn to_json_writer<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"[")?;
+        for (index, entry) in self.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",")?;
+            }
+            entry.to_json_writer(writer)?;
+        }
+        writer.write_all(b"]")
     }
     /// Returns `Ok(())` for an [Array](https://docs.serde.rs/serde_json/value/enum.Value.html#variant.Array).
     ///
-    /// Returns Err([JsonableError::IncompatibleEntryForType]) if the entries in the array cannot be converted to T.
+    /// Returns Err([JsonableError::IncompatibleEntryAt]) with the index of the first entry that cannot be converted to T.
     ///
     /// Returns Err([JsonableError::IncompatibleJsonType]) if the json value is not an array.
     fn validate_json(json: &Value) -> Result<()> {
         match json {
             Value::Array(vec) => {
-                if vec.into_iter().all(|entry| match T::validate_json(&entry) {
-                    Ok(_) => true,
-                    Err(_) => false,
-                }) {
-                    Ok(())
-                } else {
-                    Err(JsonableError::IncompatibleEntryForType(
-                        std::any::type_name::<T>(),
-                    ))
+                for (index, entry) in vec.iter().enumerate() {
+                    T::validate_json(entry).map_err(|err| JsonableError::IncompatibleEntryAt {
+                        index,
+                        error: Box::from(err),
+                    })?;
                 }
+
+                Ok(())
             }
             Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
                 got: "bool",
@@ -199,74 +639,28 @@ impl<T: Jsonable> Jsonable for Vec<T> {
             }),
         }
     }
-}
-
-impl<I, T> Jsonable for HashMap<I, T>
-where
-    I: From<String> + Into<String> + Hash + Eq + Clone,
-    T: Jsonable,
-    String: From<I>,
-{
-    fn from_json_unchecked(json: Value) -> Self {
-        let obj = json
-            .as_object()
-            .unwrap_or_else(|| panic!("Tried converting non-object json to HashMap"));
-        let mut map = HashMap::with_capacity(obj.keys().len());
-        for (key, value) in obj.into_iter() {
-            map.insert(
-                I::from(key.to_owned()),
-                T::from_json_unchecked(value.to_owned()),
-            );
-        }
-
-        map
-    }
 
-    fn to_json(&self) -> Value {
-        let mut obj = Map::with_capacity(self.keys().len());
-        for (key, value) in self.into_iter() {
-            let k = key.clone().into();
-            obj.insert(k, value.to_json());
+    /// Returns an [JsonableError::IncompatibleEntryAt] for every entry that cannot be converted to T, in index order.
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        match json {
+            Value::Array(vec) => vec
+                .iter()
+                .enumerate()
+                .filter_map(|(index, entry)| {
+                    T::validate_json(entry)
+                        .err()
+                        .map(|err| JsonableError::IncompatibleEntryAt {
+                            index,
+                            error: Box::from(err),
+                        })
+                })
+                .collect(),
+            _ => Self::validate_json(json).err().into_iter().collect(),
         }
-
-        Value::Object(obj)
     }
 
-    fn validate_json(json: &Value) -> Result<()> {
-        match json {
-            Value::Object(map) => {
-                if map.values().all(|value| match T::validate_json(value) {
-                    Ok(()) => true,
-                    _ => false,
-                }) {
-                    Ok(())
-                } else {
-                    Err(JsonableError::IncompatibleEntryForType(
-                        std::any::type_name::<T>(),
-                    ))
-                }
-            }
-            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "array",
-                expected: "object",
-            }),
-            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "bool",
-                expected: "object",
-            }),
-            Value::Null => Err(JsonableError::IncompatibleJsonType {
-                got: "null",
-                expected: "object",
-            }),
-            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "number",
-                expected: "object",
-            }),
-            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "string",
-                expected: "object",
-            }),
-        }
+    fn json_schema() -> Value {
+        schema::definition(None, || schema::array(T::json_schema()))
     }
 }
 
@@ -286,6 +680,10 @@ where
         set
     }
 
+    fn from_json_fused(json: Value) -> Result<Self> {
+        Vec::<T>::from_json_fused(json).map(|vec| vec.into_iter().collect())
+    }
+
     fn to_json(&self) -> Value {
         let mut vec = Vec::new();
 
@@ -296,9 +694,86 @@ where
         Value::Array(vec)
     }
 
+    fn into_json(self) -> Value {
+        Value::Array(self.into_iter().map(T::into_json).collect())
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        Vec::<T>::validate_json(json)
+    }
+
+    fn json_schema() -> Value {
+        let mut schema = schema::definition(None, || schema::array(T::json_schema()));
+        schema["uniqueItems"] = true.into();
+        schema
+    }
+}
+
+/// Written in sorted order, so equal sets always produce the same json.
+impl<T> Jsonable for BTreeSet<T>
+where
+    T: Jsonable + Ord,
+{
+    fn from_json_unchecked(json: Value) -> Self {
+        Vec::<T>::from_json_unchecked(json).into_iter().collect()
+    }
+
+    fn from_json_fused(json: Value) -> Result<Self> {
+        Vec::<T>::from_json_fused(json).map(|vec| vec.into_iter().collect())
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(|entry| entry.to_json()).collect())
+    }
+
+    fn into_json(self) -> Value {
+        Value::Array(self.into_iter().map(T::into_json).collect())
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        Vec::<T>::validate_json(json)
+    }
+
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        Vec::<T>::validate_json_all(json)
+    }
+
+    fn json_schema() -> Value {
+        let mut schema = schema::definition(None, || schema::array(T::json_schema()));
+        schema["uniqueItems"] = true.into();
+        schema
+    }
+}
+
+/// Written front to back.
+impl<T: Jsonable> Jsonable for VecDeque<T> {
+    fn from_json_unchecked(json: Value) -> Self {
+        Vec::<T>::from_json_unchecked(json).into()
+    }
+
+    fn from_json_fused(json: Value) -> Result<Self> {
+        Vec::<T>::from_json_fused(json).map(VecDeque::from)
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(|entry| entry.to_json()).collect())
+    }
+
+    fn into_json(self) -> Value {
+        Value::Array(self.into_iter().map(T::into_json).collect())
+    }
+
     fn validate_json(json: &Value) -> Result<()> {
         Vec::<T>::validate_json(json)
     }
+
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        Vec::<T>::validate_json_all(json)
+    }
+
+    fn json_schema() -> Value {
+        Vec::<T>::json_schema()
+    }
 }
 
 impl<T> Jsonable for Option<T>
@@ -312,6 +787,13 @@ where
         }
     }
 
+    fn from_json_fused(json: Value) -> Result<Self> {
+        match json {
+            Value::Null => Ok(None),
+            _ => T::from_json_fused(json).map(Some),
+        }
+    }
+
     fn to_json(&self) -> Value {
         if let Some(value) = self {
             value.to_json()
@@ -320,328 +802,2855 @@ where
         }
     }
 
+    fn into_json(self) -> Value {
+        self.map_or(Value::Null, T::into_json)
+    }
+
     fn validate_json(json: &Value) -> Result<()> {
         match json {
             Value::Null => Ok(()),
             _ => T::validate_json(json),
         }
     }
-}
 
-impl Jsonable for String {
-    fn from_json_unchecked(json: Value) -> Self {
-        json.as_str()
-            .unwrap_or_else(|| panic!("Tried converting non-string json into string"))
-            .into()
+    fn json_schema() -> Value {
+        schema::definition(None, || schema::nullable(T::json_schema()))
     }
+}
 
-    fn to_json(&self) -> Value {
-        Value::String(self.clone())
-    }
+/// Smart pointers are stored as the value they point to.
+///
+/// Sharing is not preserved: several `Rc`s or `Arc`s pointing at the same allocation are each
+/// written as their own copy of the value, and reading always creates a new allocation per value.
+macro_rules! pointer_impl {
+    ($ptr: ident) => {
+        impl<T: Jsonable> Jsonable for $ptr<T> {
+            fn from_json_fused(json: Value) -> Result<Self> {
+                T::from_json_fused(json).map($ptr::new)
+            }
 
-    fn validate_json(json: &Value) -> Result<()> {
-        match json {
-            Value::String(_) => Ok(()),
-            Value::Null => Err(JsonableError::IncompatibleJsonType {
-                got: "null",
-                expected: "string",
-            }),
-            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "bool",
-                expected: "string",
-            }),
-            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "number",
-                expected: "string",
-            }),
-            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "array",
-                expected: "string",
-            }),
-            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "object",
-                expected: "string",
-            }),
+            fn from_json_unchecked(json: Value) -> Self {
+                $ptr::new(T::from_json_unchecked(json))
+            }
+
+            fn to_json(&self) -> Value {
+                T::to_json(self)
+            }
+
+            fn to_json_writer<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                T::to_json_writer(self, writer)
+            }
+
+            fn validate_json(json: &Value) -> Result<()> {
+                T::validate_json(json)
+            }
+
+            fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+                T::validate_json_all(json)
+            }
+
+            fn schema_ref_name() -> Option<&'static str> {
+                T::schema_ref_name()
+            }
+
+            fn json_schema() -> Value {
+                T::json_schema()
+            }
+        }
+    };
+}
+
+pointer_impl!(Box);
+pointer_impl!(Rc);
+pointer_impl!(Arc);
+
+/// Stored the same as the owned form, so `Cow<str>` is a string and `Cow<[T]>` an array.
+///
+/// Reading always produces `Cow::Owned`, since the value cannot borrow from the json.
+impl<T> Jsonable for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Jsonable,
+{
+    fn from_json_fused(json: Value) -> Result<Self> {
+        T::Owned::from_json_fused(json).map(Cow::Owned)
+    }
+
+    fn from_json_unchecked(json: Value) -> Self {
+        Cow::Owned(T::Owned::from_json_unchecked(json))
+    }
+
+    /// Borrowed values are cloned into their owned form to be converted.
+    fn to_json(&self) -> Value {
+        match self {
+            Cow::Borrowed(value) => (*value).to_owned().to_json(),
+            Cow::Owned(value) => value.to_json(),
         }
     }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        T::Owned::validate_json(json)
+    }
+
+    fn json_schema() -> Value {
+        T::Owned::json_schema()
+    }
 }
 
-impl<T: Jsonable, const N: usize> Jsonable for [T; N] {
-    fn from_json_unchecked(mut json: Value) -> Self {
-        json.as_array_mut()
-            .unwrap_or_else(|| panic!("Tried converting non-array json to fixed sized array"))
-            .to_owned()
-            .into_iter()
-            .map(|value| T::from_json_unchecked(value))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap_or_else(|v: Vec<T>| {
-                panic!("Expected Vec or length {}. Got {} instead", N, v.len())
-            })
+/// Externally tagged, matching serde: `{ "Ok": <T> }` or `{ "Err": <E> }`.
+impl<T, E> Jsonable for core::result::Result<T, E>
+where
+    T: Jsonable,
+    E: Jsonable,
+{
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an object with an `Ok` or `Err` key
+    fn from_json_unchecked(json: Value) -> Self {
+        let mut map = match json {
+            Value::Object(map) => map,
+            _ => panic!("Tried converting non-object json to Result"),
+        };
+
+        if let Some(value) = map.remove("Ok") {
+            Ok(T::from_json_unchecked(value))
+        } else if let Some(value) = map.remove("Err") {
+            Err(E::from_json_unchecked(value))
+        } else {
+            panic!("Tried converting json without an 'Ok' or 'Err' key to Result")
+        }
     }
 
     fn to_json(&self) -> Value {
-        Value::Array(
-            self.into_iter()
-                .map(|value| value.to_json())
-                .collect::<Vec<_>>(),
-        )
+        let (key, value) = match self {
+            Ok(value) => ("Ok", value.to_json()),
+            Err(value) => ("Err", value.to_json()),
+        };
+
+        Value::Object(Map::from_iter([(key.into(), value)]))
     }
 
+    /// Returns Err([JsonableError::InnerErrorForType]) wrapping the `Ok` or `Err` payload's error if it cannot be converted.
     fn validate_json(json: &Value) -> Result<()> {
         match json {
-            Value::Array(arr) => {
-                if arr.len() == N {
-                    if arr.into_iter().all(|value| T::validate_json(value).is_ok()) {
-                        Ok(())
-                    } else {
-                        Err(JsonableError::IncompatibleEntryForType(
-                            std::any::type_name::<T>(),
-                        ))
-                    }
+            Value::Object(map) => {
+                if map.len() != 1 {
+                    return Err(JsonableError::IncorrectObjectKeyCountForEnum {
+                        ty: std::any::type_name::<Self>(),
+                        count: map.len(),
+                    });
+                }
+
+                if let Some(value) = map.get("Ok") {
+                    T::validate_json(value).map_err(|err| JsonableError::InnerErrorForType {
+                        ty: std::any::type_name::<T>(),
+                        error: Box::from(err),
+                    })
+                } else if let Some(value) = map.get("Err") {
+                    E::validate_json(value).map_err(|err| JsonableError::InnerErrorForType {
+                        ty: std::any::type_name::<E>(),
+                        error: Box::from(err),
+                    })
                 } else {
-                    Err(JsonableError::InvalidArrayLength {
-                        got: arr.len(),
-                        expected: N,
+                    Err(JsonableError::IncorrectKeyForEnum {
+                        ty: std::any::type_name::<Self>(),
+                        key: map.keys().next().unwrap().clone(),
+                        expected: vec!["Ok", "Err"],
                     })
                 }
             }
-            Value::Null => Err(JsonableError::IncompatibleJsonType {
-                got: "null",
-                expected: "array",
-            }),
-            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "string",
-                expected: "array",
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "object",
             }),
             Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
                 got: "bool",
-                expected: "array",
+                expected: "object",
+            }),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "object",
             }),
             Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
                 got: "number",
-                expected: "array",
+                expected: "object",
             }),
-            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
-                got: "object",
-                expected: "array",
+            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "string",
+                expected: "object",
             }),
         }
     }
+
+    fn json_schema() -> Value {
+        schema::definition(None, || {
+            schema::externally_tagged(
+                vec![
+                    ("Ok", Some(T::json_schema())),
+                    ("Err", Some(E::json_schema())),
+                ],
+                false,
+            )
+        })
+    }
 }
 
-macro_rules! number_impl {
-    ($ty: ty, $method: ident) => {
-        impl Jsonable for $ty {
-            fn from_json_unchecked(json: Value) -> Self {
-                json.$method().unwrap_or_else(|| {
-                    panic!(
-                        "Tried converting non-number json to {}",
-                        std::any::type_name::<$ty>()
-                    )
-                }) as $ty
-            }
+/// Externally tagged, matching serde: `{ "Continue": <C> }` or `{ "Break": <B> }`.
+impl<B, C> Jsonable for ControlFlow<B, C>
+where
+    B: Jsonable,
+    C: Jsonable,
+{
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an object with a `Continue` or `Break` key
+    fn from_json_unchecked(json: Value) -> Self {
+        let mut map = match json {
+            Value::Object(map) => map,
+            _ => panic!("Tried converting non-object json to ControlFlow"),
+        };
 
-            fn to_json(&self) -> Value {
-                Value::from(*self)
-            }
+        if let Some(value) = map.remove("Continue") {
+            ControlFlow::Continue(C::from_json_unchecked(value))
+        } else if let Some(value) = map.remove("Break") {
+            ControlFlow::Break(B::from_json_unchecked(value))
+        } else {
+            panic!("Tried converting json without a 'Continue' or 'Break' key to ControlFlow")
+        }
+    }
 
-            fn validate_json(json: &Value) -> Result<()> {
-                match json {
-                    Value::Number(_) => Ok(()),
-                    Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
-                        got: "array",
-                        expected: "number",
-                    }),
-                    Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
-                        got: "bool",
-                        expected: "number",
-                    }),
-                    Value::Null => Err(JsonableError::IncompatibleJsonType {
-                        got: "null",
-                        expected: "number",
-                    }),
-                    Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
-                        got: "object",
-                        expected: "number",
-                    }),
-                    Value::String(_) => Err(JsonableError::IncompatibleJsonType {
-                        got: "string",
-                        expected: "number",
-                    }),
+    fn to_json(&self) -> Value {
+        let (key, value) = match self {
+            ControlFlow::Continue(value) => ("Continue", value.to_json()),
+            ControlFlow::Break(value) => ("Break", value.to_json()),
+        };
+
+        Value::Object(Map::from_iter([(key.into(), value)]))
+    }
+
+    /// Returns Err([JsonableError::InnerErrorForType]) wrapping the `Continue` or `Break` payload's error if it cannot be converted.
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Object(map) => {
+                if map.len() != 1 {
+                    return Err(JsonableError::IncorrectObjectKeyCountForEnum {
+                        ty: std::any::type_name::<Self>(),
+                        count: map.len(),
+                    });
+                }
+
+                if let Some(value) = map.get("Continue") {
+                    C::validate_json(value).map_err(|err| JsonableError::InnerErrorForType {
+                        ty: std::any::type_name::<C>(),
+                        error: Box::from(err),
+                    })
+                } else if let Some(value) = map.get("Break") {
+                    B::validate_json(value).map_err(|err| JsonableError::InnerErrorForType {
+                        ty: std::any::type_name::<B>(),
+                        error: Box::from(err),
+                    })
+                } else {
+                    Err(JsonableError::IncorrectKeyForEnum {
+                        ty: std::any::type_name::<Self>(),
+                        key: map.keys().next().unwrap().clone(),
+                        expected: vec!["Continue", "Break"],
+                    })
                 }
             }
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "object",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "object",
+            }),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "object",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "object",
+            }),
+            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "string",
+                expected: "object",
+            }),
         }
-    };
+    }
+
+    fn json_schema() -> Value {
+        schema::definition(None, || {
+            schema::externally_tagged(
+                vec![
+                    ("Continue", Some(C::json_schema())),
+                    ("Break", Some(B::json_schema())),
+                ],
+                false,
+            )
+        })
+    }
 }
 
-number_impl!(u8, as_u64);
-number_impl!(u16, as_u64);
-number_impl!(u32, as_u64);
-number_impl!(u64, as_u64);
-number_impl!(usize, as_u64);
-number_impl!(i8, as_i64);
-number_impl!(i16, as_i64);
-number_impl!(i32, as_i64);
-number_impl!(isize, as_i64);
-number_impl!(i64, as_i64);
-number_impl!(f32, as_f64);
-number_impl!(f64, as_f64);
+impl Jsonable for bool {
+    fn from_json_unchecked(json: Value) -> Self {
+        json.as_bool().unwrap()
+    }
 
-#[cfg(test)]
-pub mod tests {
-    pub use super::*;
-    pub use serde_json::*;
+    fn to_json(&self) -> Value {
+        Value::Bool(*self)
+    }
 
-    // Enabled test module
-    #[allow(unused_macros)]
-    macro_rules! test_mod {
-        ($name:ident { $( $rest:tt )* }) => {
-            mod $name {
-                pub use super::*;
-                $($rest)*
-            }
-        };
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Bool(_) => Ok(()),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "bool",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "bool",
+            }),
+            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "string",
+                expected: "bool",
+            }),
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "bool",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "bool",
+            }),
+        }
     }
 
-    // Disabled test module
-    #[allow(unused_macros)]
-    macro_rules! xtest_mod {
-        ($name:ident { $( $rest:tt )* }) => {};
+    fn json_schema() -> Value {
+        json!({ "type": "boolean" })
     }
+}
 
-    test_mod! { fixed_array {
-        pub type Subject = [u8;4];
+impl Jsonable for String {
+    fn from_json_unchecked(json: Value) -> Self {
+        json.as_str()
+            .unwrap_or_else(|| panic!("Tried converting non-string json into string"))
+            .into()
+    }
 
-        test_mod!{ from_json_unchecked {
-            #[test]
-            fn happy_path() {
-                let result = Subject::from_json_unchecked(json!([1,2,3,4]));
-                assert_eq!(result, [1, 2, 3, 4]);
-            }
+    fn to_json(&self) -> Value {
+        Value::String(self.clone())
+    }
+
+    fn into_json(self) -> Value {
+        Value::String(self)
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::String(_) => Ok(()),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "string",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "string",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "string",
+            }),
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "string",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "string",
+            }),
+        }
+    }
+
+    fn json_schema() -> Value {
+        json!({ "type": "string" })
+    }
+}
+
+/// Stored the same as `String`.
+impl Jsonable for Box<str> {
+    fn from_json_unchecked(json: Value) -> Self {
+        String::from_json_unchecked(json).into_boxed_str()
+    }
+
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        String::validate_json(json)
+    }
+
+    fn json_schema() -> Value {
+        String::json_schema()
+    }
+}
+
+/// Stored as a string.
+///
+/// Paths that are not valid UTF-8 are written lossily, with invalid sequences replaced by
+/// `U+FFFD`, since json strings cannot hold them.
+impl Jsonable for PathBuf {
+    fn from_json_unchecked(json: Value) -> Self {
+        PathBuf::from(String::from_json_unchecked(json))
+    }
+
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string_lossy().into_owned())
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        String::validate_json(json)
+    }
+
+    fn json_schema() -> Value {
+        String::json_schema()
+    }
+}
+
+/// A json string holding exactly one character.
+impl Jsonable for char {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a non-empty string.
+    /// Only the first character is kept.
+    fn from_json_unchecked(json: Value) -> Self {
+        json.as_str()
+            .and_then(|value| value.chars().next())
+            .unwrap_or_else(|| panic!("Tried converting non-string or empty string json into char"))
+    }
+
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+
+    /// Returns Err([JsonableError::InvalidFormat]) for an empty or multi-character string.
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::String(value) => {
+                if value.chars().count() == 1 {
+                    Ok(())
+                } else {
+                    Err(JsonableError::InvalidFormat {
+                        expected: "single character string",
+                        got: value.clone(),
+                    })
+                }
+            }
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "string",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "string",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "string",
+            }),
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "string",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "string",
+            }),
+        }
+    }
+
+    fn json_schema() -> Value {
+        json!({ "type": "string", "minLength": 1 })
+    }
+}
+
+/// Matches unit structs: `()` is `null`.
+///
+/// `Option<()>` therefore reads `Some(())` back as `None`, as both are written as `null`.
+impl Jsonable for () {
+    fn from_json_unchecked(json: Value) -> Self {
+        json.as_null()
+            .unwrap_or_else(|| panic!("Tried converting non-null json to ()"))
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Null
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Null => Ok(()),
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "null",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "null",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "null",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "null",
+            }),
+            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "string",
+                expected: "null",
+            }),
+        }
+    }
+
+    fn json_schema() -> Value {
+        json!({ "type": "null" })
+    }
+}
+
+impl<T: Jsonable, const N: usize> Jsonable for [T; N] {
+    fn from_json_unchecked(json: Value) -> Self {
+        let entries = match json {
+            Value::Array(entries) => entries,
+            _ => panic!("Tried converting non-array json to fixed sized array"),
+        };
+
+        entries
+            .into_iter()
+            .map(T::from_json_unchecked)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap_or_else(|v: Vec<T>| {
+                panic!("Expected Vec or length {}. Got {} instead", N, v.len())
+            })
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Array(
+            self.into_iter()
+                .map(|value| value.to_json())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Returns Err([JsonableError::InvalidArrayLength]) if the array is not `N` long.
+    ///
+    /// Returns Err([JsonableError::IncompatibleEntryAt]) with the index of the first entry that cannot be converted to T.
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Array(arr) => {
+                if arr.len() == N {
+                    Vec::<T>::validate_json(json)
+                } else {
+                    Err(JsonableError::InvalidArrayLength {
+                        got: arr.len(),
+                        expected: N,
+                    })
+                }
+            }
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "array",
+            }),
+            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "string",
+                expected: "array",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "array",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "array",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "array",
+            }),
+        }
+    }
+
+    fn json_schema() -> Value {
+        let mut schema = schema::definition(None, || schema::array(T::json_schema()));
+        schema["minItems"] = N.into();
+        schema["maxItems"] = N.into();
+        schema
+    }
+}
+
+/// Checks that `number` is an integer that fits in `T`.
+fn validate_integer<T: TryFrom<u64> + TryFrom<i64>>(number: &Number) -> Result<()> {
+    let in_range = if let Some(value) = number.as_u64() {
+        T::try_from(value).is_ok()
+    } else if let Some(value) = number.as_i64() {
+        T::try_from(value).is_ok()
+    } else {
+        return Err(JsonableError::IncompatibleJsonType {
+            got: "float",
+            expected: "integer",
+        });
+    };
+
+    if in_range {
+        Ok(())
+    } else {
+        Err(JsonableError::NumberOutOfRange {
+            ty: std::any::type_name::<T>(),
+            value: number.clone(),
+        })
+    }
+}
+
+macro_rules! number_impl {
+    ($ty: ty, $method: ident) => {
+        impl Jsonable for $ty {
+            fn from_json_unchecked(json: Value) -> Self {
+                json.$method().unwrap_or_else(|| {
+                    panic!(
+                        "Tried converting non-number json to {}",
+                        std::any::type_name::<$ty>()
+                    )
+                }) as $ty
+            }
+
+            fn to_json(&self) -> Value {
+                Value::from(*self)
+            }
+
+            fn validate_json(json: &Value) -> Result<()> {
+                match json {
+                    Value::Number(number) => validate_integer::<$ty>(number),
+                    Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "array",
+                        expected: "number",
+                    }),
+                    Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "bool",
+                        expected: "number",
+                    }),
+                    Value::Null => Err(JsonableError::IncompatibleJsonType {
+                        got: "null",
+                        expected: "number",
+                    }),
+                    Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "object",
+                        expected: "number",
+                    }),
+                    Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "string",
+                        expected: "number",
+                    }),
+                }
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "integer", "minimum": <$ty>::MIN, "maximum": <$ty>::MAX })
+            }
+        }
+    };
+}
+
+number_impl!(u8, as_u64);
+number_impl!(u16, as_u64);
+number_impl!(u32, as_u64);
+number_impl!(u64, as_u64);
+number_impl!(usize, as_u64);
+number_impl!(i8, as_i64);
+number_impl!(i16, as_i64);
+number_impl!(i32, as_i64);
+number_impl!(isize, as_i64);
+number_impl!(i64, as_i64);
+
+/// Finite values are json numbers. `NaN` and the infinities, which json numbers cannot hold,
+/// are the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`, so every float read back is equal
+/// to the one written, also inside collections such as `Vec<f64>`.
+macro_rules! float_impl {
+    ($ty: ty) => {
+        impl Jsonable for $ty {
+            /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is neither a number nor a non-finite string
+            fn from_json_unchecked(json: Value) -> Self {
+                match &json {
+                    Value::String(value) => non_finite::parse(value),
+                    _ => json.as_f64(),
+                }
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Tried converting non-number json to {}",
+                        std::any::type_name::<$ty>()
+                    )
+                }) as $ty
+            }
+
+            fn to_json(&self) -> Value {
+                non_finite::to_json(*self as f64)
+            }
+
+            /// Returns Err([JsonableError::InvalidFormat]) for strings other than `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+            fn validate_json(json: &Value) -> Result<()> {
+                match json {
+                    Value::Number(_) => Ok(()),
+                    Value::String(value) => match non_finite::parse(value) {
+                        Some(_) => Ok(()),
+                        None => Err(JsonableError::InvalidFormat {
+                            expected: non_finite::EXPECTED,
+                            got: value.clone(),
+                        }),
+                    },
+                    Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "array",
+                        expected: "number",
+                    }),
+                    Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "bool",
+                        expected: "number",
+                    }),
+                    Value::Null => Err(JsonableError::IncompatibleJsonType {
+                        got: "null",
+                        expected: "number",
+                    }),
+                    Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "object",
+                        expected: "number",
+                    }),
+                }
+            }
+
+            fn json_schema() -> Value {
+                non_finite::json_schema()
+            }
+        }
+    };
+}
+
+float_impl!(f32);
+float_impl!(f64);
+
+/// `NonZero*` integers are stored as plain numbers, rejecting `0`.
+macro_rules! non_zero_impl {
+    ($ty: ty, $int: ty) => {
+        impl Jsonable for $ty {
+            /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a number or is `0`
+            fn from_json_unchecked(json: Value) -> Self {
+                <$ty>::new(<$int>::from_json_unchecked(json)).unwrap_or_else(|| {
+                    panic!("Tried converting 0 to {}", std::any::type_name::<$ty>())
+                })
+            }
+
+            fn to_json(&self) -> Value {
+                self.get().to_json()
+            }
+
+            /// Returns Err([JsonableError::ZeroForNonZero]) for `0`, otherwise validates like the underlying integer.
+            fn validate_json(json: &Value) -> Result<()> {
+                <$int>::validate_json(json)?;
+                if json.as_u64() == Some(0) {
+                    return Err(JsonableError::ZeroForNonZero {
+                        ty: std::any::type_name::<$ty>(),
+                    });
+                }
+
+                Ok(())
+            }
+
+            fn json_schema() -> Value {
+                let mut schema = <$int>::json_schema();
+                schema["not"] = json!({ "const": 0 });
+                schema
+            }
+        }
+    };
+}
+
+non_zero_impl!(NonZeroU8, u8);
+non_zero_impl!(NonZeroU16, u16);
+non_zero_impl!(NonZeroU32, u32);
+non_zero_impl!(NonZeroU64, u64);
+non_zero_impl!(NonZeroUsize, usize);
+non_zero_impl!(NonZeroI8, i8);
+non_zero_impl!(NonZeroI16, i16);
+non_zero_impl!(NonZeroI32, i32);
+non_zero_impl!(NonZeroI64, i64);
+non_zero_impl!(NonZeroIsize, isize);
+
+/// Tuples are json arrays with one entry per element, in order.
+macro_rules! tuple_impl {
+    ($len: expr => $($name: ident $index: tt),+) => {
+        impl<$($name: Jsonable),+> Jsonable for ($($name,)+) {
+            /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an array of the tuple's length
+            fn from_json_unchecked(json: Value) -> Self {
+                let mut values = match json {
+                    Value::Array(values) if values.len() == $len => values.into_iter(),
+                    _ => panic!(
+                        "Tried converting json other than an array of length {} to a tuple",
+                        $len
+                    ),
+                };
+
+                ($($name::from_json_unchecked(values.next().unwrap()),)+)
+            }
+
+            fn to_json(&self) -> Value {
+                Value::Array(vec![$(self.$index.to_json()),+])
+            }
+
+            /// Returns Err([JsonableError::InvalidArrayLength]) if the array is not the tuple's length.
+            ///
+            /// Returns Err([JsonableError::IncompatibleEntryAt]) with the index of the first element that cannot be converted.
+            fn validate_json(json: &Value) -> Result<()> {
+                match json {
+                    Value::Array(values) => {
+                        if values.len() != $len {
+                            return Err(JsonableError::InvalidArrayLength {
+                                got: values.len(),
+                                expected: $len,
+                            });
+                        }
+
+                        $(
+                            $name::validate_json(&values[$index]).map_err(|err| {
+                                JsonableError::IncompatibleEntryAt {
+                                    index: $index,
+                                    error: Box::from(err),
+                                }
+                            })?;
+                        )+
+
+                        Ok(())
+                    }
+                    Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "bool",
+                        expected: "array",
+                    }),
+                    Value::Null => Err(JsonableError::IncompatibleJsonType {
+                        got: "null",
+                        expected: "array",
+                    }),
+                    Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "number",
+                        expected: "array",
+                    }),
+                    Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "object",
+                        expected: "array",
+                    }),
+                    Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "string",
+                        expected: "array",
+                    }),
+                }
+            }
+
+            fn json_schema() -> Value {
+                schema::definition(None, || schema::tuple(vec![$($name::json_schema()),+]))
+            }
+        }
+    };
+}
+
+tuple_impl!(2 => A 0, B 1);
+tuple_impl!(3 => A 0, B 1, C 2);
+tuple_impl!(4 => A 0, B 1, C 2, D 3);
+tuple_impl!(5 => A 0, B 1, C 2, D 3, E 4);
+tuple_impl!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_impl!(7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_impl!(8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+tuple_impl!(9 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+tuple_impl!(10 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+tuple_impl!(11 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+tuple_impl!(12 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// Types stored as the string written by `Display` and read back with `FromStr`.
+///
+/// 128 bit integers do not fit in a json number without losing precision, so they are
+/// stored as decimal strings such as `"340282366920938463463374607431768211455"`.
+/// IP addresses use their usual notation, `"127.0.0.1"` or `"::1"`, and UUIDs their hyphenated
+/// form.
+macro_rules! from_str_impl {
+    ($ty: ty, $expected: literal) => {
+        impl Jsonable for $ty {
+            /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a string that parses
+            fn from_json_unchecked(json: Value) -> Self {
+                json.as_str()
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Tried converting invalid string json to {}",
+                            std::any::type_name::<$ty>()
+                        )
+                    })
+            }
+
+            fn to_json(&self) -> Value {
+                Value::String(self.to_string())
+            }
+
+            /// Returns Err([JsonableError::InvalidFormat]) if the string does not parse.
+            fn validate_json(json: &Value) -> Result<()> {
+                match json {
+                    Value::String(value) => match value.parse::<$ty>() {
+                        Ok(_) => Ok(()),
+                        Err(_) => Err(JsonableError::InvalidFormat {
+                            expected: $expected,
+                            got: value.clone(),
+                        }),
+                    },
+                    Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "array",
+                        expected: "string",
+                    }),
+                    Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "bool",
+                        expected: "string",
+                    }),
+                    Value::Null => Err(JsonableError::IncompatibleJsonType {
+                        got: "null",
+                        expected: "string",
+                    }),
+                    Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "number",
+                        expected: "string",
+                    }),
+                    Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                        got: "object",
+                        expected: "string",
+                    }),
+                }
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "string" })
+            }
+        }
+    };
+}
+
+from_str_impl!(u128, "u128 decimal string");
+from_str_impl!(i128, "i128 decimal string");
+from_str_impl!(IpAddr, "IP address");
+from_str_impl!(Ipv4Addr, "IPv4 address");
+from_str_impl!(Ipv6Addr, "IPv6 address");
+#[cfg(feature = "uuid")]
+from_str_impl!(uuid::Uuid, "UUID");
+
+/// Matches serde: `{ "secs": <u64>, "nanos": <u32> }`.
+impl Jsonable for Duration {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an object with both keys
+    fn from_json_unchecked(mut json: Value) -> Self {
+        let map = json
+            .as_object_mut()
+            .unwrap_or_else(|| panic!("Tried converting non-object json to Duration"));
+        let secs = map.remove("secs").unwrap_or(Value::Null);
+        let nanos = map.remove("nanos").unwrap_or(Value::Null);
+        Duration::new(
+            u64::from_json_unchecked(secs),
+            u32::from_json_unchecked(nanos),
+        )
+    }
+
+    fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        map.insert("secs".into(), self.as_secs().to_json());
+        map.insert("nanos".into(), self.subsec_nanos().to_json());
+        Value::Object(map)
+    }
+
+    /// Returns Err([JsonableError::MissingRequiredKey]) if either key is absent and
+    /// Err([JsonableError::NumberOutOfRange]) if `nanos` is `1_000_000_000` or more.
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Object(map) => {
+                validate_duration_key::<u64>(map, "secs")?;
+                validate_duration_key::<u32>(map, "nanos")?;
+
+                match map["nanos"].as_u64() {
+                    Some(nanos) if nanos >= 1_000_000_000 => Err(JsonableError::NumberOutOfRange {
+                        ty: std::any::type_name::<Duration>(),
+                        value: Number::from(nanos),
+                    }),
+                    _ => Ok(()),
+                }
+            }
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "object",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "object",
+            }),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "object",
+            }),
+            Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "number",
+                expected: "object",
+            }),
+            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "string",
+                expected: "object",
+            }),
+        }
+    }
+
+    fn json_schema() -> Value {
+        schema::ObjectSchema::new()
+            .property("secs", u64::json_schema(), true)
+            .property(
+                "nanos",
+                json!({ "type": "integer", "minimum": 0, "maximum": 999_999_999 }),
+                true,
+            )
+            .build(false)
+    }
+}
+
+fn validate_duration_key<T: Jsonable>(map: &Map<String, Value>, key: &'static str) -> Result<()> {
+    match map.get(key) {
+        Some(value) => T::validate_json(value).map_err(|err| JsonableError::InnerErrorForType {
+            ty: std::any::type_name::<T>(),
+            error: Box::from(err),
+        }),
+        None => Err(JsonableError::MissingRequiredKey { key: key.into() }),
+    }
+}
+
+/// Whole milliseconds since the unix epoch.
+///
+/// Times before the epoch cannot be stored: [Jsonable::to_json] panics on them, and negative
+/// numbers are rejected. Use `#[jsonable(with = "jsonable::rfc3339")]` for fields that may hold
+/// such times.
+impl Jsonable for SystemTime {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a non-negative integer
+    fn from_json_unchecked(json: Value) -> Self {
+        UNIX_EPOCH + Duration::from_millis(u64::from_json_unchecked(json))
+    }
+
+    /// Panics if `self` is before the unix epoch. Sub-millisecond precision is dropped.
+    fn to_json(&self) -> Value {
+        let since_epoch = self.duration_since(UNIX_EPOCH).unwrap_or_else(|_| {
+            panic!("Tried converting SystemTime before the unix epoch to json")
+        });
+        Value::from(since_epoch.as_millis() as u64)
+    }
+
+    /// Returns Err([JsonableError::NumberOutOfRange]) for a negative number, a time before the epoch.
+    fn validate_json(json: &Value) -> Result<()> {
+        u64::validate_json(json).map_err(|err| match err {
+            JsonableError::NumberOutOfRange { value, .. } => JsonableError::NumberOutOfRange {
+                ty: std::any::type_name::<SystemTime>(),
+                value,
+            },
+            err => err,
+        })
+    }
+
+    fn json_schema() -> Value {
+        u64::json_schema()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    pub use super::*;
+    pub use serde_json::*;
+    pub use std::collections::{BTreeMap, HashMap};
+
+    // Enabled test module
+    #[allow(unused_macros)]
+    macro_rules! test_mod {
+        ($name:ident { $( $rest:tt )* }) => {
+            mod $name {
+                pub use super::*;
+                $($rest)*
+            }
+        };
+    }
+
+    // Disabled test module
+    #[allow(unused_macros)]
+    macro_rules! xtest_mod {
+        ($name:ident { $( $rest:tt )* }) => {};
+    }
+
+    test_mod! { bool {
+        pub type Subject = bool;
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path() {
+                assert!(Subject::from_json_unchecked(json!(true)));
+                assert!(!Subject::from_json_unchecked(json!(false)));
+            }
+
+            #[test]
+            #[should_panic]
+            fn incorrect_json_type() {
+                Subject::from_json_unchecked(json!("true"));
+            }
+        }}
+
+        test_mod!{ to_json {
+            #[test]
+            fn happy_path() {
+                assert_eq!(true.to_json(), json!(true));
+                assert_eq!(false.to_json(), json!(false));
+            }
+        }}
+
+        test_mod!{ validate_json {
+            #[test]
+            fn happy_path() {
+                assert!(Subject::validate_json(&json!(true)).is_ok());
+                assert!(Subject::validate_json(&json!(false)).is_ok());
+            }
+
+            #[test]
+            fn incorrect_json_type() {
+                let cases = [
+                    (json!(null), "null"),
+                    (json!(1), "number"),
+                    (json!("true"), "string"),
+                    (json!([]), "array"),
+                    (json!({}), "object"),
+                ];
+
+                for (json, got) in cases {
+                    assert_eq!(Subject::validate_json(&json), Err(JsonableError::IncompatibleJsonType { got, expected: "bool" }));
+                }
+            }
+        }}
+    }}
+
+    test_mod! { boxed {
+        pub type Subject = Box<u32>;
+
+        #[test]
+        fn round_trip() {
+            let subject: Subject = Box::new(7);
+            assert_eq!(subject.to_json(), json!(7));
+            assert_eq!(Subject::from_json(subject.to_json()), Ok(subject));
+        }
+
+        #[test]
+        fn validate_json() {
+            assert_eq!(Subject::validate_json(&json!("7")), Err(JsonableError::IncompatibleJsonType { got: "string", expected: "number" }));
+        }
+
+        #[test]
+        fn boxed_str() {
+            let subject: Box<str> = "Still alive".into();
+            assert_eq!(subject.to_json(), json!("Still alive"));
+            assert_eq!(Box::<str>::from_json(subject.to_json()), Ok(subject));
+            assert_eq!(Box::<str>::validate_json(&json!(7)), Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
+        }
+    }}
+
+    test_mod! { btree_map {
+        pub use std::collections::BTreeMap;
+        pub type Subject = BTreeMap<String, u8>;
+
+        #[test]
+        fn round_trip() {
+            let subject = Subject::from_iter([("a".into(), 1), ("b".into(), 2)]);
+            assert_eq!(subject.to_json(), json!({"a": 1, "b": 2}));
+            assert_eq!(Subject::from_json(subject.to_json()), Ok(subject));
+        }
+
+        #[test]
+        fn insertion_order_does_not_matter() {
+            let mut first = Subject::new();
+            for (key, value) in [("zulu", 1), ("alpha", 2), ("mike", 3)] {
+                first.insert(key.into(), value);
+            }
+            let mut second = Subject::new();
+            for (key, value) in [("mike", 3), ("zulu", 1), ("alpha", 2)] {
+                second.insert(key.into(), value);
+            }
+
+            assert_eq!(to_string(&first.to_json()).unwrap(), to_string(&second.to_json()).unwrap());
+            assert_eq!(to_string(&first.to_json()).unwrap(), r#"{"alpha":2,"mike":3,"zulu":1}"#);
+        }
+
+        #[test]
+        fn invalid_value() {
+            assert_eq!(Subject::validate_json(&json!({"a": "one"})), Err(JsonableError::InvalidMapValue {
+                key: "a".into(),
+                error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            }));
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(Subject::validate_json(&json!([])), Err(JsonableError::IncompatibleJsonType { got: "array", expected: "object" }));
+        }
+    }}
+
+    test_mod! { btree_set {
+        pub use std::collections::BTreeSet;
+        pub type Subject = BTreeSet<u8>;
+
+        #[test]
+        fn sorted_output() {
+            let subject = Subject::from_iter([3, 1, 2]);
+            assert_eq!(subject.to_json(), json!([1, 2, 3]));
+        }
+
+        #[test]
+        fn round_trip() {
+            let subject = Subject::from_iter([3, 1, 2]);
+            assert_eq!(Subject::from_json(json!([2, 3, 1, 3])), Ok(subject));
+        }
+
+        #[test]
+        fn invalid_entry() {
+            assert_eq!(Subject::validate_json(&json!([1, "two"])), Err(JsonableError::IncompatibleEntryAt {
+                index: 1,
+                error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            }));
+        }
+    }}
+
+    test_mod! { char {
+        pub type Subject = char;
+
+        #[test]
+        fn round_trip() {
+            for value in ['a', 'é', '€', '𝄞'] {
+                assert_eq!(Subject::from_json(value.to_json()), Ok(value));
+            }
+        }
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path() {
+                assert_eq!(Subject::from_json_unchecked(json!("€")), '€');
+            }
+
+            #[test]
+            #[should_panic]
+            fn empty_string() {
+                Subject::from_json_unchecked(json!(""));
+            }
+        }}
+
+        test_mod!{ to_json {
+            #[test]
+            fn happy_path() {
+                assert_eq!('€'.to_json(), json!("€"));
+            }
+        }}
+
+        test_mod!{ validate_json {
+            #[test]
+            fn happy_path() {
+                assert!(Subject::validate_json(&json!("a")).is_ok());
+                assert!(Subject::validate_json(&json!("€")).is_ok());
+            }
+
+            #[test]
+            fn incorrect_json_type() {
+                let result = Subject::validate_json(&json!(1));
+                assert_eq!(result, Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
+            }
+
+            #[test]
+            fn incorrect_length() {
+                // "e\u{301}" renders as one character but is two scalar values
+                for value in ["", "ab", "€€", "e\u{301}"] {
+                    let result = Subject::validate_json(&json!(value));
+                    assert_eq!(result, Err(JsonableError::InvalidFormat { expected: "single character string", got: value.into() }));
+                }
+            }
+        }}
+    }}
+
+    // Round trips and error paths for generic types built out of several impls
+    test_mod! { composition {
+        test_mod!{ option_result {
+            pub type Subject = Option<core::result::Result<Vec<u8>, String>>;
+
+            #[test]
+            fn round_trip_ok() {
+                let subject: Subject = Some(Ok(vec![1, 2, 3]));
+                let json = subject.to_json();
+
+                assert_eq!(json, json!({"Ok": [1, 2, 3]}));
+                assert_eq!(Subject::from_json(json), Ok(subject));
+            }
+
+            #[test]
+            fn round_trip_err() {
+                let subject: Subject = Some(Err("Nope".into()));
+                let json = subject.to_json();
+
+                assert_eq!(json, json!({"Err": "Nope"}));
+                assert_eq!(Subject::from_json(json), Ok(subject));
+            }
+
+            #[test]
+            fn round_trip_none() {
+                let subject: Subject = None;
+                let json = subject.to_json();
+
+                assert_eq!(json, json!(null));
+                assert_eq!(Subject::from_json(json), Ok(subject));
+            }
+
+            #[test]
+            fn inner_error() {
+                let result = Subject::validate_json(&json!({"Ok": [1, "two", 3]}));
+
+                assert_eq!(result, Err(JsonableError::InnerErrorForType {
+                    ty: std::any::type_name::<Vec<u8>>(),
+                    error: Box::from(JsonableError::IncompatibleEntryAt {
+                        index: 1,
+                        error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+                    })
+                }));
+            }
+
+            #[test]
+            fn inner_error_for_err() {
+                let result = Subject::validate_json(&json!({"Err": 12}));
+
+                assert_eq!(result, Err(JsonableError::InnerErrorForType {
+                    ty: std::any::type_name::<String>(),
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "number", expected: "string" })
+                }));
+            }
+        }}
+
+        test_mod!{ vec_option {
+            pub type Subject = Vec<Option<u8>>;
+
+            #[test]
+            fn round_trip() {
+                let subject: Subject = vec![Some(1), None, Some(3)];
+                let json = subject.to_json();
+
+                assert_eq!(json, json!([1, null, 3]));
+                assert_eq!(Subject::from_json(json), Ok(subject));
+            }
+
+            #[test]
+            fn inner_error() {
+                let result = Subject::validate_json(&json!([1, null, "three"]));
+
+                assert_eq!(result, Err(JsonableError::IncompatibleEntryAt {
+                    index: 2,
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+                }));
+            }
+        }}
+
+        test_mod!{ char {
+            pub use std::collections::HashMap;
+
+            pub fn too_long() -> JsonableError {
+                JsonableError::InvalidFormat { expected: "single character string", got: "ab".into() }
+            }
+
+            #[test]
+            fn vec() {
+                let subject = vec!['a', '€', '🦀'];
+                let json = subject.to_json();
+
+                assert_eq!(json, json!(["a", "€", "🦀"]));
+                assert_eq!(Vec::<char>::from_json(json), Ok(subject));
+                assert_eq!(
+                    Vec::<char>::validate_json(&json!(["a", "b", "ab"])),
+                    Err(JsonableError::IncompatibleEntryAt { index: 2, error: Box::from(too_long()) })
+                );
+            }
+
+            #[test]
+            fn option() {
+                assert_eq!(Option::<char>::from_json(json!("€")), Ok(Some('€')));
+                assert_eq!(Option::<char>::from_json(json!(null)), Ok(None));
+                assert_eq!(Option::<char>::validate_json(&json!("ab")), Err(too_long()));
+            }
+
+            #[test]
+            fn hash_map() {
+                let mut subject = HashMap::<String, char>::new();
+                subject.insert("delimiter".into(), ',');
+                let json = subject.to_json();
+
+                assert_eq!(json, json!({"delimiter": ","}));
+                assert_eq!(HashMap::<String, char>::from_json(json), Ok(subject));
+                assert_eq!(
+                    HashMap::<String, char>::validate_json(&json!({"delimiter": "ab"})),
+                    Err(JsonableError::InvalidMapValue { key: "delimiter".into(), error: Box::from(too_long()) })
+                );
+            }
+        }}
+
+        test_mod!{ hash_map_result {
+            pub use std::collections::HashMap;
+            pub type Subject = HashMap<String, core::result::Result<u8, String>>;
+
+            #[test]
+            fn round_trip() {
+                let mut subject = Subject::new();
+                subject.insert("good".into(), Ok(1));
+                subject.insert("bad".into(), Err("Uh oh".into()));
+                let json = subject.to_json();
+
+                assert_eq!(json, json!({"good": {"Ok": 1}, "bad": {"Err": "Uh oh"}}));
+                assert_eq!(Subject::from_json(json), Ok(subject));
+            }
+
+            #[test]
+            fn inner_error() {
+                let result = Subject::validate_json(&json!({"good": {"Ok": 1}, "bad": {"Err": 2}}));
+
+                assert_eq!(result, Err(JsonableError::InvalidMapValue {
+                    key: "bad".into(),
+                    error: Box::from(JsonableError::InnerErrorForType {
+                        ty: std::any::type_name::<String>(),
+                        error: Box::from(JsonableError::IncompatibleJsonType { got: "number", expected: "string" })
+                    })
+                }));
+            }
+
+            #[test]
+            fn inner_error_unknown_key() {
+                let result = Subject::validate_json(&json!({"good": {"Maybe": 1}}));
+
+                assert_eq!(result, Err(JsonableError::InvalidMapValue {
+                    key: "good".into(),
+                    error: Box::from(JsonableError::IncorrectKeyForEnum {
+                        ty: std::any::type_name::<core::result::Result<u8, String>>(),
+                        key: "Maybe".into(),
+                        expected: vec!["Ok", "Err"]
+                    })
+                }));
+            }
+        }}
+    }}
+
+    test_mod! { control_flow {
+        pub type Subject = std::ops::ControlFlow<String, u8>;
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path_continue() {
+                let result = Subject::from_json_unchecked(json!({"Continue": 8}));
+                assert_eq!(result, std::ops::ControlFlow::Continue(8));
+            }
+
+            #[test]
+            fn happy_path_break() {
+                let result = Subject::from_json_unchecked(json!({"Break": "Done"}));
+                assert_eq!(result, std::ops::ControlFlow::Break("Done".into()));
+            }
+
+            #[test]
+            #[should_panic]
+            fn incorrect_json_type() {
+                let _ = Subject::from_json_unchecked(json!([]));
+            }
+
+            #[test]
+            #[should_panic]
+            fn unknown_key() {
+                let _ = Subject::from_json_unchecked(json!({"Pause": 8}));
+            }
+        }}
+
+        test_mod!{ to_json {
+            #[test]
+            fn happy_path_continue() {
+                let subject: Subject = std::ops::ControlFlow::Continue(8);
+                assert_eq!(subject.to_json(), json!({"Continue": 8}));
+            }
+
+            #[test]
+            fn happy_path_break() {
+                let subject: Subject = std::ops::ControlFlow::Break("Done".into());
+                assert_eq!(subject.to_json(), json!({"Break": "Done"}));
+            }
+        }}
+
+        test_mod!{ validate_json {
+            #[test]
+            fn happy_path() {
+                assert!(Subject::validate_json(&json!({"Continue": 8})).is_ok());
+                assert!(Subject::validate_json(&json!({"Break": "Done"})).is_ok());
+            }
+
+            #[test]
+            fn incorrect_payload() {
+                let result = Subject::validate_json(&json!({"Break": 8}));
+                assert_eq!(result, Err(JsonableError::InnerErrorForType { ty: std::any::type_name::<String>(), error: Box::from(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }) }));
+            }
+
+            #[test]
+            fn incorrect_json_type() {
+                let result = Subject::validate_json(&json!("Continue"));
+                assert_eq!(result, Err(JsonableError::IncompatibleJsonType { got: "string", expected: "object" }));
+            }
+
+            #[test]
+            fn incorrect_key_count() {
+                let result = Subject::validate_json(&json!({"Continue": 8, "Break": "Done"}));
+                assert_eq!(result, Err(JsonableError::IncorrectObjectKeyCountForEnum { ty: std::any::type_name::<Subject>(), count: 2 }));
+            }
+
+            #[test]
+            fn unknown_key() {
+                let result = Subject::validate_json(&json!({"Pause": 8}));
+                assert_eq!(result, Err(JsonableError::IncorrectKeyForEnum { ty: std::any::type_name::<Subject>(), key: "Pause".into(), expected: vec!["Continue", "Break"] }));
+            }
+        }}
+    }}
+
+    test_mod! { cow {
+        pub use std::borrow::Cow;
+
+        #[test]
+        fn borrowed_str_round_trip() {
+            let subject: Cow<str> = Cow::Borrowed("x");
+            assert_eq!(subject.to_json(), json!("x"));
+
+            let result = Cow::<str>::from_json(subject.to_json()).unwrap();
+            assert!(matches!(result, Cow::Owned(ref value) if value == "x"));
+        }
+
+        #[test]
+        fn slice() {
+            let subject: Cow<[u8]> = Cow::Borrowed(&[1, 2]);
+            assert_eq!(subject.to_json(), json!([1, 2]));
+            assert_eq!(Cow::<[u8]>::from_json(json!([1, 2])), Ok(subject));
+        }
+
+        #[test]
+        fn validate_json() {
+            assert_eq!(Cow::<str>::validate_json(&json!(1)), Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
+        }
+    }}
+
+    #[cfg(feature = "chrono")]
+    test_mod! { datetime {
+        pub use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+        #[test]
+        fn date_time_utc() {
+            let subject = Utc.with_ymd_and_hms(2024, 1, 31, 12, 30, 0).unwrap();
+            assert_eq!(subject.to_json(), json!("2024-01-31T12:30:00Z"));
+            assert_eq!(DateTime::<Utc>::from_json(subject.to_json()), Ok(subject));
+            assert_eq!(DateTime::<Utc>::from_json(json!("2024-01-31T14:30:00+02:00")), Ok(subject));
+        }
+
+        #[test]
+        fn naive() {
+            let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+            assert_eq!(date.to_json(), json!("2024-01-31"));
+            assert_eq!(NaiveDate::from_json(date.to_json()), Ok(date));
+
+            let time = date.and_hms_milli_opt(12, 30, 0, 250).unwrap();
+            assert_eq!(time.to_json(), json!("2024-01-31T12:30:00.250"));
+            assert_eq!(NaiveDateTime::from_json(time.to_json()), Ok(time));
+        }
+
+        #[test]
+        fn malformed() {
+            assert_eq!(
+                DateTime::<Utc>::validate_json(&json!("2024-02-30T00:00:00Z")),
+                Err(JsonableError::InvalidFormat { expected: "RFC 3339 date and time", got: "2024-02-30T00:00:00Z".into() })
+            );
+            assert_eq!(
+                NaiveDate::validate_json(&json!("31/01/2024")),
+                Err(JsonableError::InvalidFormat { expected: "ISO 8601 date", got: "31/01/2024".into() })
+            );
+            assert_eq!(
+                NaiveDateTime::validate_json(&json!(1706704200)),
+                Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" })
+            );
+        }
+    }}
+
+    #[cfg(feature = "diff")]
+    test_mod! { diff {
+        #[test]
+        fn replaced_entry() {
+            let before: BTreeMap<String, u8> = BTreeMap::from([("a".into(), 1), ("b".into(), 2)]);
+            let after: BTreeMap<String, u8> = BTreeMap::from([("a".into(), 1), ("b".into(), 3)]);
+
+            assert_eq!(before.diff(&after), json!([{ "op": "replace", "path": "/b", "value": 3 }]));
+        }
+
+        #[test]
+        fn added_entry() {
+            let before: BTreeMap<String, u8> = BTreeMap::from([("a".into(), 1)]);
+            let after: BTreeMap<String, u8> = BTreeMap::from([("a".into(), 1), ("b".into(), 2)]);
+
+            assert_eq!(before.diff(&after), json!([{ "op": "add", "path": "/b", "value": 2 }]));
+        }
+
+        #[test]
+        fn identical() {
+            let subject: Vec<u8> = vec![1, 2, 3];
+            assert_eq!(subject.diff(&subject.clone()), json!([]));
+        }
+    }}
+
+    test_mod! { display {
+        #[test]
+        fn single() {
+            let error = JsonableError::IncompatibleJsonType { got: "string", expected: "number" };
+            assert_eq!(error.to_string(), "expected a JSON number but got string");
+        }
+
+        #[test]
+        fn nested() {
+            let error = JsonableError::InnerErrorForType {
+                ty: "Person",
+                error: Box::from(JsonableError::InvalidMapValue {
+                    key: "age".into(),
+                    error: Box::from(JsonableError::NumberOutOfRange { ty: "u8", value: Number::from(300) }),
+                }),
+            };
+            assert_eq!(error.to_string(), "Person -> `age` -> 300 is out of range for u8");
+        }
+
+        #[test]
+        fn several() {
+            let error = JsonableError::InnerErrorsForType {
+                ty: "Shape",
+                errors: vec![
+                    JsonableError::IncompatibleEntryAt { index: 1, error: Box::from(JsonableError::IncompatibleJsonType { got: "null", expected: "string" }) },
+                    JsonableError::MissingKeyForEnumVariant { variant: "Circle", key: "radius" },
+                ],
+            };
+            assert_eq!(error.to_string(), "Shape -> ([1] -> expected a JSON string but got null; missing key `radius` for variant Circle)");
+        }
+
+        #[test]
+        fn unknown_field() {
+            let error = JsonableError::UnknownField { ty: "Config", field: "typo".into() };
+            assert_eq!(error.to_string(), "unknown field `typo` for Config");
+        }
+
+        #[test]
+        fn no_untagged_variant_matched() {
+            let error = JsonableError::NoUntaggedVariantMatched { ty: "Id" };
+            assert_eq!(error.to_string(), "json matches no variant of Id");
+        }
+
+        #[test]
+        fn incorrect_key_for_enum() {
+            let error = JsonableError::IncorrectKeyForEnum { ty: "Light", key: "Blue".into(), expected: vec!["Red", "Green"] };
+            assert_eq!(error.to_string(), "unknown variant `Blue` of Light, expected one of: Red, Green");
+        }
+
+        #[test]
+        fn parse() {
+            let error = JsonableError::Parse("EOF while parsing a value at line 1 column 0".into());
+            assert_eq!(error.to_string(), "invalid json: EOF while parsing a value at line 1 column 0");
+        }
+
+        #[test]
+        fn serde() {
+            let error = JsonableError::Serde("missing field `id`".into());
+            assert_eq!(error.to_string(), "missing field `id`");
+        }
+
+        #[test]
+        fn invalid_enum_discriminant() {
+            let error = JsonableError::InvalidEnumDiscriminant { enum_type: "Level", got: Number::from(7), expected: vec![0, 1] };
+            assert_eq!(error.to_string(), "unknown discriminant 7 of Level, expected one of: 0, 1");
+        }
+    }}
+
+    test_mod! { duration {
+        pub type Subject = Duration;
+
+        #[test]
+        fn round_trip() {
+            let subject = Subject::new(90, 500_000_000);
+            assert_eq!(subject.to_json(), json!({ "secs": 90, "nanos": 500_000_000 }));
+            assert_eq!(Subject::from_json(subject.to_json()), Ok(subject));
+            assert_eq!(Subject::from_json(json!({ "secs": 0, "nanos": 0 })), Ok(Subject::ZERO));
+        }
+
+        #[test]
+        fn nanos_out_of_range() {
+            assert_eq!(Subject::validate_json(&json!({ "secs": 1, "nanos": 1_000_000_000 })), Err(JsonableError::NumberOutOfRange { ty: std::any::type_name::<Duration>(), value: Number::from(1_000_000_000) }));
+        }
+
+        #[test]
+        fn missing_key() {
+            assert_eq!(Subject::validate_json(&json!({ "nanos": 0 })), Err(JsonableError::MissingRequiredKey { key: "secs".into() }));
+            assert_eq!(Subject::validate_json(&json!({ "secs": 0 })), Err(JsonableError::MissingRequiredKey { key: "nanos".into() }));
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(Subject::validate_json(&json!({ "secs": -1, "nanos": 0 })), Err(JsonableError::InnerErrorForType { ty: "u64", error: Box::from(JsonableError::NumberOutOfRange { ty: "u64", value: Number::from(-1) }) }));
+            assert_eq!(Subject::validate_json(&json!(90)), Err(JsonableError::IncompatibleJsonType { got: "number", expected: "object" }));
+        }
+    }}
+
+    test_mod! { error_source {
+        pub use std::error::Error;
+
+        #[test]
+        fn boxed() {
+            fn parse() -> core::result::Result<u8, Box<dyn Error>> {
+                Ok(u8::from_json(json!("8"))?)
+            }
+
+            assert_eq!(parse().unwrap_err().to_string(), "expected a JSON number but got string");
+        }
+
+        #[test]
+        fn chain() {
+            let inner = JsonableError::MissingRequiredKey { key: "port".into() };
+            let error = JsonableError::InnerErrorForType { ty: "Config", error: Box::from(JsonableError::MissingRequiredKey { key: "port".into() }) };
+            assert_eq!(error.source().unwrap().to_string(), inner.to_string());
+            assert!(error.source().unwrap().source().is_none());
+
+            let errors = JsonableError::InnerErrorsForType { ty: "Config", errors: vec![JsonableError::MissingField, inner] };
+            assert_eq!(errors.source().unwrap().to_string(), "missing required field");
+            assert!(JsonableError::InnerErrorsForType { ty: "Config", errors: vec![] }.source().is_none());
+        }
+    }}
+
+    test_mod! { fixed_array {
+        pub type Subject = [u8;4];
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path() {
+                let result = Subject::from_json_unchecked(json!([1,2,3,4]));
+                assert_eq!(result, [1, 2, 3, 4]);
+            }
+
+            #[test]
+            #[should_panic]
+            fn incorrect_json_type() {
+                Subject::from_json_unchecked(json!({}));
+            }
+
+            #[test]
+            #[should_panic]
+            fn incorrect_array_length() {
+                Subject::from_json_unchecked(json!([1, 2, 3]));
+            }
+
+            #[test]
+            fn owned_entries() {
+                let result = <[String; 2]>::from_json_unchecked(json!(["still", "alive"]));
+                assert_eq!(result, [String::from("still"), String::from("alive")]);
+            }
+        }}
+
+        test_mod!{ to_json {
+            #[test]
+            fn happy_path() {
+                let subject: Subject = [1, 2, 3, 4];
+                let json = subject.to_json();
+                assert_eq!(json, json!([1, 2, 3, 4]));
+            }
+        }}
+
+        test_mod!{ validate_json {
+            #[test]
+            fn happy_path() {
+                assert!(Subject::validate_json(&json!([1,2,3,4])).is_ok());
+            }
+
+            #[test]
+            fn incorrect_json_type() {
+                match Subject::validate_json(&json!({})) {
+                    Err(err) => {
+                        assert_eq!{ err, JsonableError::IncompatibleJsonType { expected: "array", got: "object" } }
+                    },
+                    _ => assert!(false)
+                };
+            }
+
+            #[test]
+            fn incorrect_entry() {
+                assert_eq!(Subject::validate_json(&json!([1, 2, "three", 4])), Err(JsonableError::IncompatibleEntryAt {
+                    index: 2,
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" }),
+                }));
+            }
+
+            #[test]
+            fn incorrect_length() {
+                match Subject::validate_json(&json!([1,2,3])) {
+                    Err(err) => {
+                        assert_eq!{ err, JsonableError::InvalidArrayLength { got: 3, expected: 4 } }
+                    },
+                    _ => assert!(false)
+                };
+            }
+        }}
+    }}
+
+    test_mod! { fused {
+        fn same_as_from_json<T: Jsonable + std::fmt::Debug + PartialEq>(json: Value) {
+            assert_eq!(T::from_json_fused(json.clone()), T::from_json(json));
+        }
+
+        #[test]
+        fn vec() {
+            same_as_from_json::<Vec<u8>>(json!([1, 2, 3]));
+            same_as_from_json::<Vec<u8>>(json!([1, "two", 3]));
+            same_as_from_json::<Vec<u8>>(json!({}));
+            same_as_from_json::<Vec<Vec<u8>>>(json!([[1], [2, 300]]));
+        }
+
+        #[test]
+        fn hash_map() {
+            same_as_from_json::<HashMap<String, Option<u8>>>(json!({ "a": 1, "b": null }));
+            same_as_from_json::<HashMap<String, Option<u8>>>(json!({ "a": 1, "b": "c", "d": false }));
+            same_as_from_json::<HashMap<String, u8>>(json!([]));
+        }
+
+        #[test]
+        fn hash_set() {
+            same_as_from_json::<HashSet<String>>(json!(["a", "b"]));
+            same_as_from_json::<HashSet<String>>(json!(["a", 1]));
+        }
+
+        #[test]
+        fn option() {
+            same_as_from_json::<Option<Vec<u8>>>(json!(null));
+            same_as_from_json::<Option<Vec<u8>>>(json!([1]));
+            same_as_from_json::<Option<Vec<u8>>>(json!([-1]));
+        }
+    }}
+
+    test_mod! { hash_map {
+        pub use std::collections::HashMap;
+        pub type Subject = HashMap<String, u8>;
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path() {
+                let result = Subject::from_json_unchecked(json!({
+                    "key": 1 as u8
+                }));
+
+                assert!(result.contains_key("key".into()));
+                assert_eq!(result.get("key".into()), Some(&1));
+            }
+
+            #[test]
+            fn nested() {
+                let json = json!({ "fruit": ["apple", "pear"], "empty": [] });
+                let result = HashMap::<String, Vec<String>>::from_json_unchecked(json.clone());
+
+                assert_eq!(result["fruit"], vec![String::from("apple"), String::from("pear")]);
+                assert!(result["empty"].is_empty());
+                assert_eq!(result.to_json(), json);
+            }
+
+            #[test]
+            #[should_panic]
+            fn incorrect_json_type() {
+                Subject::from_json_unchecked(json!([]));
+            }
+        }}
+
+        test_mod!{ to_json {
+            #[test]
+            fn happy_path() {
+                let mut subject: Subject = Subject::new();
+                subject.insert("key".into(), 1);
+
+                let json = subject.to_json();
+
+                assert_eq!(json, json!({"key": 1}));
+            }
+        }}
+
+        test_mod!{ to_json_writer {
+            #[test]
+            fn happy_path() {
+                let subject: HashMap<String, u32> = (0..100_000).map(|n| (format!("key {}", n), n)).collect();
+                let mut buffer: Vec<u8> = Vec::new();
+                subject.to_json_writer(&mut buffer).unwrap();
+
+                let json: Value = from_slice(&buffer).unwrap();
+                assert_eq!(HashMap::<String, u32>::from_json(json), Ok(subject));
+            }
+
+            #[test]
+            fn empty() {
+                let mut buffer: Vec<u8> = Vec::new();
+                Subject::new().to_json_writer(&mut buffer).unwrap();
+
+                assert_eq!(buffer, b"{}");
+            }
+
+            #[test]
+            fn escaped_key() {
+                let mut subject: Subject = Subject::new();
+                subject.insert("say \"hi\"\n".into(), 1);
+                let mut buffer: Vec<u8> = Vec::new();
+                subject.to_json_writer(&mut buffer).unwrap();
+
+                assert_eq!(String::from_utf8(buffer).unwrap(), r#"{"say \"hi\"\n":1}"#);
+            }
+        }}
+
+        test_mod!{ validate_json {
+            #[test]
+            fn happy_path() {
+                let result = Subject::validate_json(&json!({
+                    "key": 1 as u8
+                }));
+                assert!(result.is_ok());
+            }
+
+            #[test]
+            fn incorrect_json_type() {
+                let result = Subject::validate_json(&json!([]));
+
+                match result {
+                    Err(err) => {
+                        assert_eq!(err, JsonableError::IncompatibleJsonType { got: "array", expected: "object" })
+                    },
+                    _ => assert!(false)
+                };
+            }
+
+            #[test]
+            fn invalid_value() {
+                let result = Subject::validate_json(&json!({"key": 1, "other": "two"}));
+
+                assert_eq!(result, Err(JsonableError::InvalidMapValue {
+                    key: "other".into(),
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+                }));
+            }
+
+            #[test]
+            fn nested_invalid_value() {
+                let result = HashMap::<String, Subject>::validate_json(&json!({
+                    "outer": {"inner": 1},
+                    "broken": {"fine": 2, "deepest": []}
+                }));
+
+                assert_eq!(result, Err(JsonableError::InvalidMapValue {
+                    key: "broken".into(),
+                    error: Box::from(JsonableError::InvalidMapValue {
+                        key: "deepest".into(),
+                        error: Box::from(JsonableError::IncompatibleJsonType { got: "array", expected: "number" })
+                    })
+                }));
+            }
+        }}
+
+        test_mod!{ validate_json_all {
+            #[test]
+            fn happy_path() {
+                assert_eq!(Subject::validate_json_all(&json!({"a": 1, "b": 2})), vec![]);
+            }
+
+            #[test]
+            fn sorted_by_key() {
+                let mut map = Map::new();
+                for key in ["zebra", "fine", "apple", "mango"] {
+                    map.insert(key.into(), if key == "fine" { json!(1) } else { json!(key) });
+                }
+
+                let keys: Vec<String> = Subject::validate_json_all(&Value::Object(map))
+                    .into_iter()
+                    .map(|err| match err {
+                        JsonableError::InvalidMapValue { key, .. } => key,
+                        other => panic!("Expected InvalidMapValue, got {:?}", other)
+                    })
+                    .collect();
+                assert_eq!(keys, vec!["apple", "mango", "zebra"]);
+            }
+
+            #[test]
+            fn incorrect_json_type() {
+                assert_eq!(
+                    Subject::validate_json_all(&json!([])),
+                    vec![JsonableError::IncompatibleJsonType { got: "array", expected: "object" }]
+                );
+            }
+        }}
+    }}
+
+    test_mod! {hash_set {
+        pub use std::collections::HashSet;
+        pub type Subject = HashSet<String>;
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path() {
+                let values: Vec<String> = vec!["Value 1".into(), "Value 2".into()];
+                let json = Value::Array(values.clone().into_iter().map(|value| Value::String(value)).collect::<Vec<_>>());
+                let subject = Subject::from_json_unchecked(json);
+
+                assert_eq!(subject.len(), values.len());
+                for value in values.iter() {
+                    assert!(subject.contains(value));
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn incorrect_json_type() {
+                Subject::from_json_unchecked(json!({}));
+            }
+        }}
+
+        test_mod!{ to_json {
+            #[test]
+            fn happy_path() {
+                let mut subject = Subject::new();
+                subject.insert("Hello".into());
+                subject.insert("World".into());
+
+                let json = subject.to_json();
+
+                // HashSet does not return keys in a consistent order
+                // Assertions must not depend on order
+                assert!(json.is_array());
+                let vec = json.as_array().unwrap();
+                assert!(vec.contains(&json!("Hello")));
+                assert!(vec.contains(&json!("World")));
+            }
+        }}
+
+        test_mod!{ validate_json {
+            #[test]
+            fn happy_path() {
+                let values: Vec<String> = vec!["Value 1".into(), "Value 2".into()];
+                let json = Value::Array(values.clone().into_iter().map(|value| Value::String(value)).collect::<Vec<_>>());
+
+                assert!(Subject::validate_json(&json).is_ok());
+            }
 
             #[test]
-            #[should_panic]
             fn incorrect_json_type() {
-                Subject::from_json_unchecked(json!({}));
+                let result = Subject::validate_json(&json!({}));
+
+                match result {
+                    Err(err) => {
+                        assert_eq!(err, JsonableError::IncompatibleJsonType { got: "object", expected: "array" })
+                    },
+                    _ => assert!(false)
+                };
+            }
+        }}
+    }}
+
+    #[cfg(feature = "indexmap")]
+    test_mod! { index_map {
+        pub use indexmap::{IndexMap, IndexSet};
+
+        #[test]
+        fn keeps_key_order() {
+            let subject = IndexMap::<String, u8>::from_json_str(r#"{"b":1,"c":2,"a":3}"#).unwrap();
+            assert_eq!(subject.keys().collect::<Vec<_>>(), ["b", "c", "a"]);
+            assert_eq!(subject.to_json_string(), r#"{"b":1,"c":2,"a":3}"#);
+            assert_eq!(subject.into_json().to_string(), r#"{"b":1,"c":2,"a":3}"#);
+        }
+
+        #[test]
+        fn invalid_value() {
+            assert_eq!(
+                IndexMap::<String, u8>::validate_json(&json!({ "a": 1, "b": "two" })),
+                Err(JsonableError::InvalidMapValue { key: "b".into(), error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" }) })
+            );
+        }
+
+        #[test]
+        fn set_keeps_order() {
+            let subject = IndexSet::<u8>::from_json(json!([3, 1, 3, 2])).unwrap();
+            assert_eq!(subject.iter().collect::<Vec<_>>(), [&3, &1, &2]);
+            assert_eq!(subject.to_json(), json!([3, 1, 2]));
+        }
+    }}
+
+    test_mod! { insert_if_present {
+        #[test]
+        fn some() {
+            let mut map = Map::new();
+            insert_if_present(&mut map, "count", &Some(3u8));
+
+            assert_eq!(Value::Object(map), json!({"count": 3}));
+        }
+
+        #[test]
+        fn none() {
+            let mut map = Map::new();
+            insert_if_present::<u8>(&mut map, "count", &None);
+
+            assert!(map.is_empty());
+        }
+    }}
+
+    test_mod! { into_json {
+        #[test]
+        fn string() {
+            assert_eq!(String::from("moved").into_json(), json!("moved"));
+        }
+
+        #[test]
+        fn collections() {
+            let strings = vec![String::from("a"), String::from("b")];
+            assert_eq!(strings.clone().into_json(), strings.to_json());
+            assert_eq!(VecDeque::from(strings.clone()).into_json(), json!(["a", "b"]));
+            assert_eq!(Some(strings).into_json(), json!(["a", "b"]));
+            assert_eq!(None::<String>.into_json(), Value::Null);
+        }
+
+        #[test]
+        fn maps() {
+            let map = HashMap::from([(String::from("key"), String::from("value"))]);
+            assert_eq!(map.into_json(), json!({"key": "value"}));
+
+            let map = BTreeMap::from([(Cow::Borrowed("key"), vec![1u8])]);
+            assert_eq!(map.into_json(), json!({"key": [1]}));
+        }
+
+        #[test]
+        fn default_falls_back_to_to_json() {
+            assert_eq!(7u8.into_json(), json!(7));
+        }
+    }}
+
+    test_mod! { ip_addr {
+        pub use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        #[test]
+        fn round_trip() {
+            let v4 = Ipv4Addr::new(192, 168, 0, 1);
+            assert_eq!(v4.to_json(), json!("192.168.0.1"));
+            assert_eq!(Ipv4Addr::from_json(v4.to_json()), Ok(v4));
+
+            let v6 = Ipv6Addr::LOCALHOST;
+            assert_eq!(v6.to_json(), json!("::1"));
+            assert_eq!(Ipv6Addr::from_json(v6.to_json()), Ok(v6));
+        }
+
+        #[test]
+        fn either_version() {
+            assert_eq!(IpAddr::from_json(json!("10.0.0.1")), Ok(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))));
+            assert_eq!(IpAddr::from_json(json!("fe80::1")), Ok(IpAddr::V6("fe80::1".parse().unwrap())));
+        }
+
+        #[test]
+        fn invalid_format() {
+            assert_eq!(IpAddr::validate_json(&json!("not.an.ip")), Err(JsonableError::InvalidFormat { expected: "IP address", got: "not.an.ip".into() }));
+            assert_eq!(Ipv4Addr::validate_json(&json!("::1")), Err(JsonableError::InvalidFormat { expected: "IPv4 address", got: "::1".into() }));
+            assert_eq!(Ipv6Addr::validate_json(&json!(1)), Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
+        }
+    }}
+
+    #[cfg(feature = "iso8601")]
+    test_mod! { iso8601_duration {
+        pub use std::time::Duration;
+        pub use crate::iso8601::duration as subject;
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path() {
+                assert_eq!(subject::from_json_unchecked(json!("PT1H30M")), Duration::from_secs(5400));
+                assert_eq!(subject::from_json_unchecked(json!("P1DT0.5S")), Duration::from_millis(86_400_500));
+                assert_eq!(subject::from_json_unchecked(json!("P2W")), Duration::from_secs(1_209_600));
             }
 
             #[test]
             #[should_panic]
-            fn incorrect_array_length() {
-                Subject::from_json_unchecked(json!([1, 2, 3]));
+            fn malformed() {
+                subject::from_json_unchecked(json!("1H30M"));
             }
         }}
 
         test_mod!{ to_json {
             #[test]
             fn happy_path() {
-                let subject: Subject = [1, 2, 3, 4];
-                let json = subject.to_json();
-                assert_eq!(json, json!([1, 2, 3, 4]));
+                assert_eq!(subject::to_json(&Duration::from_secs(5400)), json!("PT1H30M"));
+                assert_eq!(subject::to_json(&Duration::from_millis(90_061_250)), json!("PT25H1M1.25S"));
+                assert_eq!(subject::to_json(&Duration::ZERO), json!("PT0S"));
             }
         }}
 
         test_mod!{ validate_json {
             #[test]
             fn happy_path() {
-                assert!(Subject::validate_json(&json!([1,2,3,4])).is_ok());
+                assert!(subject::validate_json(&json!("PT1H30M")).is_ok());
+                assert!(subject::validate_json(&json!("P1DT2H3M4.5S")).is_ok());
             }
 
             #[test]
             fn incorrect_json_type() {
-                match Subject::validate_json(&json!({})) {
-                    Err(err) => {
-                        assert_eq!{ err, JsonableError::IncompatibleJsonType { expected: "array", got: "object" } }
-                    },
-                    _ => assert!(false)
-                };
+                let result = subject::validate_json(&json!(5400));
+                assert_eq!(result, Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
+            }
+
+            #[test]
+            fn malformed() {
+                for value in ["", "P", "PT", "1H", "P1Y", "P1M", "PT1D", "PT1.5M", "PT30M1H", "PT1H1H", "PT1,S"] {
+                    let result = subject::validate_json(&json!(value));
+                    assert_eq!(result, Err(JsonableError::InvalidFormat { expected: "ISO 8601 duration", got: value.into() }));
+                }
+            }
+        }}
+    }}
+
+    test_mod! { items_constraint {
+        pub use crate::constraints::items as subject;
+
+        #[test]
+        fn happy_path() {
+            assert!(subject(&json!([1, 2, 3]), Some(1), Some(3), true).is_ok());
+            assert!(subject(&json!([1, 1]), None, None, false).is_ok());
+        }
+
+        #[test]
+        fn too_short() {
+            assert_eq!(subject(&json!([]), Some(1), None, false), Err(JsonableError::CollectionTooShort { got: 0, min: 1 }));
+        }
+
+        #[test]
+        fn too_long() {
+            assert_eq!(subject(&json!([1, 2, 3]), None, Some(2), false), Err(JsonableError::CollectionTooLong { got: 3, max: 2 }));
+        }
+
+        #[test]
+        fn duplicate() {
+            assert_eq!(subject(&json!([1, 2, 1]), None, None, true), Err(JsonableError::DuplicateElement { index: 2 }));
+        }
+    }}
+
+    test_mod! { json_schema {
+        #[test]
+        fn primitives() {
+            assert_eq!(bool::json_schema(), json!({ "type": "boolean" }));
+            assert_eq!(String::json_schema(), json!({ "type": "string" }));
+            assert_eq!(u8::json_schema(), json!({ "type": "integer", "minimum": 0, "maximum": 255 }));
+            assert_eq!(<()>::json_schema(), json!({ "type": "null" }));
+        }
+
+        #[test]
+        fn option() {
+            assert_eq!(
+                Option::<String>::json_schema(),
+                json!({ "anyOf": [{ "type": "string" }, { "type": "null" }] })
+            );
+        }
+
+        #[test]
+        fn collections() {
+            assert_eq!(
+                Vec::<bool>::json_schema(),
+                json!({ "type": "array", "items": { "type": "boolean" } })
+            );
+            assert_eq!(
+                HashMap::<String, bool>::json_schema(),
+                json!({ "type": "object", "additionalProperties": { "type": "boolean" } })
+            );
+            assert_eq!(
+                <(bool, String)>::json_schema(),
+                json!({
+                    "type": "array",
+                    "prefixItems": [{ "type": "boolean" }, { "type": "string" }],
+                    "items": false,
+                    "minItems": 2,
+                    "maxItems": 2,
+                })
+            );
+        }
+
+        #[test]
+        fn result() {
+            assert_eq!(
+                core::result::Result::<bool, String>::json_schema(),
+                json!({ "oneOf": [
+                    { "type": "object", "properties": { "Ok": { "type": "boolean" } }, "required": ["Ok"], "additionalProperties": false },
+                    { "type": "object", "properties": { "Err": { "type": "string" } }, "required": ["Err"], "additionalProperties": false },
+                ] })
+            );
+        }
+    }}
+
+    test_mod! { json_string {
+        #[test]
+        fn from_json_str() {
+            assert_eq!(Vec::<u8>::from_json_str("[1, 2]"), Ok(vec![1, 2]));
+            assert_eq!(
+                u8::from_json_str("\"one\""),
+                Err(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            );
+        }
+
+        #[test]
+        fn from_json_str_invalid() {
+            assert!(matches!(Vec::<u8>::from_json_str("[1,"), Err(JsonableError::Parse(_))));
+        }
+
+        #[test]
+        fn to_json_string() {
+            assert_eq!(vec![1u8, 2].to_json_string(), "[1,2]");
+            assert_eq!(vec![1u8, 2].to_json_string_pretty(), "[\n  1,\n  2\n]");
+        }
+    }}
+
+    test_mod! { json_wrapper {
+        #[test]
+        fn try_from() {
+            assert_eq!(Json::<Vec<u8>>::try_from(json!([1, 2])), Ok(Json(vec![1, 2])));
+        }
+
+        #[test]
+        fn try_from_invalid() {
+            assert_eq!(
+                Json::<u8>::try_from(json!("one")),
+                Err(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            );
+        }
+
+        #[test]
+        fn into_value() {
+            let json: Value = Json(vec![1u8, 2]).into();
+            assert_eq!(json, json!([1, 2]));
+        }
+    }}
+
+    test_mod! { max_bytes_constraint {
+        pub use crate::constraints::max_bytes as subject;
+
+        #[test]
+        fn happy_path() {
+            // {"a":1} is 7 bytes
+            assert!(subject(&json!({ "a": 1 }), 7).is_ok());
+            assert!(subject(&json!("abc"), 5).is_ok());
+        }
+
+        #[test]
+        fn too_large() {
+            assert_eq!(subject(&json!({ "a": 1 }), 6), Err(JsonableError::PayloadTooLarge { got: 7, max: 6 }));
+            assert_eq!(subject(&json!([1, 2, 3]), 0), Err(JsonableError::PayloadTooLarge { got: 7, max: 0 }));
+        }
+    }}
+
+    test_mod! { merge_json {
+        #[test]
+        fn merges_objects() {
+            let mut subject: BTreeMap<String, Vec<u8>> = BTreeMap::from([("a".into(), vec![1]), ("b".into(), vec![2, 3])]);
+            subject.merge_json(json!({ "b": [4], "c": [] })).unwrap();
+
+            assert_eq!(subject, BTreeMap::from([("a".into(), vec![1]), ("b".into(), vec![4]), ("c".into(), vec![])]));
+        }
+
+        #[test]
+        fn nested_objects() {
+            let mut subject: HashMap<String, HashMap<String, u8>> = HashMap::from([("inner".into(), HashMap::from([("a".into(), 1), ("b".into(), 2)]))]);
+            subject.merge_json(json!({ "inner": { "b": 3 } })).unwrap();
+
+            assert_eq!(subject["inner"], HashMap::from([("a".into(), 1), ("b".into(), 3)]));
+        }
+
+        #[test]
+        fn invalid_result_is_unchanged() {
+            let mut subject: Vec<u8> = vec![1, 2];
+            assert_eq!(
+                subject.merge_json(json!("three")),
+                Err(JsonableError::IncompatibleJsonType { got: "string", expected: "array" })
+            );
+            assert_eq!(subject, vec![1, 2]);
+        }
+    }}
+
+    test_mod! { non_finite_float {
+        pub type Subject = f64;
+
+        #[test]
+        fn round_trip() {
+            for value in [f64::INFINITY, f64::NEG_INFINITY, 1.5] {
+                assert_eq!(Subject::from_json(value.to_json()), Ok(value));
+            }
+            assert!(Subject::from_json(f64::NAN.to_json()).unwrap().is_nan());
+            assert_eq!(f32::from_json(f32::NEG_INFINITY.to_json()), Ok(f32::NEG_INFINITY));
+        }
+
+        #[test]
+        fn round_trip_in_collections() {
+            let values = vec![f64::INFINITY, 2.5, f64::NEG_INFINITY];
+            assert_eq!(Vec::<f64>::from_json(values.to_json()), Ok(values));
+            assert!(Option::<f64>::from_json(Some(f64::NAN).to_json()).unwrap().unwrap().is_nan());
+            let map = HashMap::from([("high".to_string(), f64::INFINITY)]);
+            assert_eq!(HashMap::<String, f64>::from_json(map.to_json()), Ok(map));
+        }
+
+        #[test]
+        fn to_json() {
+            assert_eq!(f64::NAN.to_json(), json!("NaN"));
+            assert_eq!(f64::INFINITY.to_json(), json!("Infinity"));
+            assert_eq!(f64::NEG_INFINITY.to_json(), json!("-Infinity"));
+            assert_eq!(2.5f64.to_json(), json!(2.5));
+        }
+
+        #[test]
+        fn validate_json() {
+            assert!(Subject::validate_json(&json!("Infinity")).is_ok());
+            assert!(Subject::validate_json(&json!(2)).is_ok());
+            assert_eq!(
+                Subject::validate_json(&json!("inf")),
+                Err(JsonableError::InvalidFormat { expected: "\"NaN\", \"Infinity\", or \"-Infinity\"", got: "inf".into() })
+            );
+            assert_eq!(
+                Subject::validate_json(&json!(null)),
+                Err(JsonableError::IncompatibleJsonType { got: "null", expected: "number" })
+            );
+        }
+    }}
+
+    test_mod! { non_zero {
+        pub use std::num::{NonZeroI8, NonZeroU32, NonZeroU64};
+
+        #[test]
+        fn round_trip() {
+            let subject = NonZeroU32::new(5).unwrap();
+            assert_eq!(subject.to_json(), json!(5));
+            assert_eq!(NonZeroU32::from_json(json!(5)), Ok(subject));
+            assert_eq!(NonZeroI8::from_json(json!(-3)), Ok(NonZeroI8::new(-3).unwrap()));
+        }
+
+        #[test]
+        fn zero() {
+            assert_eq!(NonZeroU32::validate_json(&json!(0)), Err(JsonableError::ZeroForNonZero { ty: std::any::type_name::<NonZeroU32>() }));
+            assert_eq!(NonZeroI8::validate_json(&json!(0)), Err(JsonableError::ZeroForNonZero { ty: std::any::type_name::<NonZeroI8>() }));
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_json_unchecked_zero() {
+            NonZeroU64::from_json_unchecked(json!(0));
+        }
+
+        #[test]
+        fn out_of_range() {
+            assert_eq!(NonZeroI8::validate_json(&json!(200)), Err(JsonableError::NumberOutOfRange { ty: "i8", value: Number::from(200) }));
+        }
+    }}
+
+    test_mod! { number_range {
+        #[test]
+        fn in_range() {
+            assert!(u8::validate_json(&json!(255)).is_ok());
+            assert!(u8::validate_json(&json!(0)).is_ok());
+            assert!(i16::validate_json(&json!(i16::MIN)).is_ok());
+            assert!(u64::validate_json(&json!(u64::MAX)).is_ok());
+        }
+
+        #[test]
+        fn too_large() {
+            assert_eq!(u8::validate_json(&json!(300)), Err(JsonableError::NumberOutOfRange { ty: "u8", value: Number::from(300) }));
+            assert_eq!(i64::validate_json(&json!(u64::MAX)), Err(JsonableError::NumberOutOfRange { ty: "i64", value: Number::from(u64::MAX) }));
+        }
+
+        #[test]
+        fn negative_unsigned() {
+            assert_eq!(u8::validate_json(&json!(-1)), Err(JsonableError::NumberOutOfRange { ty: "u8", value: Number::from(-1) }));
+        }
+
+        #[test]
+        fn float_for_integer() {
+            assert_eq!(i64::validate_json(&json!(1.5)), Err(JsonableError::IncompatibleJsonType { got: "float", expected: "integer" }));
+            assert_eq!(u8::validate_json(&json!(3.0)), Err(JsonableError::IncompatibleJsonType { got: "float", expected: "integer" }));
+        }
+
+        #[test]
+        fn integer_for_float() {
+            assert!(f64::validate_json(&json!(2)).is_ok());
+            assert!(f32::validate_json(&json!(-2)).is_ok());
+            assert!(f64::validate_json(&json!(2.5)).is_ok());
+        }
+
+        #[test]
+        fn too_small() {
+            assert_eq!(i8::validate_json(&json!(-129)), Err(JsonableError::NumberOutOfRange { ty: "i8", value: Number::from(-129) }));
+        }
+    }}
+
+    test_mod! {option {
+        pub type Subject = Option<u8>;
+
+        test_mod!{ from_json_unchecked {
+            #[test]
+            fn happy_path() {
+                let result = Subject::from_json_unchecked(json!(8));
+                assert_eq!(result, Some(8 as u8));
+            }
+            #[test]
+            fn happy_path_null() {
+                let result = Subject::from_json_unchecked(json!(null));
+                assert_eq!(result, None);
+            }
+        }}
+
+        test_mod!{ to_json {
+            #[test]
+            fn happy_path() {
+                let subject: Subject = Some(8);
+                let result = subject.to_json();
+                assert_eq!(result, json!(8));
+            }
+
+            #[test]
+            fn happy_path_null() {
+                let subject: Subject = None;
+                let result = subject.to_json();
+                assert_eq!(result, json!(null));
+            }
+        }}
+
+        test_mod!{ validate_json {
+            #[test]
+            fn happy_path() {
+                assert!(Subject::validate_json(&json!(8)).is_ok());
+            }
+
+            #[test]
+            fn happy_path_null() {
+                assert!(Subject::validate_json(&json!(null)).is_ok());
             }
+        }}
+    }}
+
+    test_mod! { path_buf {
+        pub type Subject = PathBuf;
+
+        #[test]
+        fn round_trip() {
+            let relative = Subject::from("config/app.json");
+            assert_eq!(relative.to_json(), json!("config/app.json"));
+            assert_eq!(Subject::from_json(relative.to_json()), Ok(relative));
+
+            let absolute = Subject::from("/etc/app/config.json");
+            assert_eq!(absolute.to_json(), json!("/etc/app/config.json"));
+            assert_eq!(Subject::from_json(absolute.to_json()), Ok(absolute));
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(Subject::validate_json(&json!(["etc", "app"])), Err(JsonableError::IncompatibleJsonType { got: "array", expected: "string" }));
+        }
+    }}
+
+    test_mod! { raw_number {
+        #[test]
+        fn beyond_f64_precision() {
+            let json = json!(12345678901234567890u64);
+            assert_ne!(f64::from_json(json.clone()).unwrap().to_json(), json);
+
+            let subject = RawNumber::from_json(json.clone()).unwrap();
+            assert_eq!(subject.to_json(), json);
+            assert_eq!(subject.to_string(), "12345678901234567890");
+        }
+
+        #[test]
+        fn negative() {
+            let json = json!(i64::MIN);
+            assert_eq!(RawNumber::from_json(json.clone()).unwrap().into_json(), json);
+        }
+
+        #[test]
+        fn incorrect_type() {
+            assert_eq!(
+                RawNumber::validate_json(&json!("12")),
+                Err(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            );
+        }
+
+        #[cfg(feature = "arbitrary_precision")]
+        #[test]
+        fn arbitrary_precision() {
+            let text = "123456789012345678901234567890.123456789012345678901234567890";
+            let subject = RawNumber::from_json(serde_json::from_str(text).unwrap()).unwrap();
+
+            assert_eq!(subject.to_string(), text);
+            assert_eq!(subject.to_json_string(), text);
+        }
+    }}
+
+    test_mod! { reader_writer {
+        use std::io::Cursor;
+
+        #[test]
+        fn from_reader() {
+            let reader = Cursor::new(br#"{"a": [1, 2]}"#.to_vec());
+            assert_eq!(HashMap::<String, Vec<u8>>::from_reader(reader), Ok(HashMap::from([("a".into(), vec![1, 2])])));
+        }
+
+        #[test]
+        fn from_reader_invalid() {
+            assert!(matches!(Vec::<u8>::from_reader(Cursor::new(b"[1,".to_vec())), Err(JsonableError::Parse(_))));
+            assert_eq!(
+                Vec::<u8>::from_reader(Cursor::new(b"[true]".to_vec())),
+                Err(JsonableError::IncompatibleEntryAt {
+                    index: 0,
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "bool", expected: "number" })
+                })
+            );
+        }
+
+        #[test]
+        fn to_writer() {
+            let mut cursor = Cursor::new(Vec::new());
+            vec![Some(1u8), None].to_writer(&mut cursor).unwrap();
+            assert_eq!(cursor.into_inner(), b"[1,null]");
+        }
+
+        #[test]
+        fn round_trip() {
+            let value = vec![String::from("a"), String::from("b")];
+            let mut buffer = Vec::new();
+            value.to_writer(&mut buffer).unwrap();
+            assert_eq!(Vec::<String>::from_reader(Cursor::new(buffer)), Ok(value));
+        }
+    }}
+
+    test_mod! { required_keys_constraint {
+        pub use crate::constraints::required_keys as subject;
 
-            #[test]
-            fn incorrect_length() {
-                match Subject::validate_json(&json!([1,2,3])) {
-                    Err(err) => {
-                        assert_eq!{ err, JsonableError::InvalidArrayLength { got: 3, expected: 4 } }
-                    },
-                    _ => assert!(false)
-                };
-            }
-        }}
+        #[test]
+        fn happy_path() {
+            assert!(subject(&json!({ "host": "localhost", "port": 80, "extra": true }), &["host", "port"]).is_ok());
+            assert!(subject(&json!([]), &["host"]).is_ok());
+        }
+
+        #[test]
+        fn missing_key() {
+            assert_eq!(subject(&json!({ "host": "localhost" }), &["host", "port"]), Err(JsonableError::MissingRequiredKey { key: "port".into() }));
+        }
     }}
 
-    test_mod! { hash_map {
-        pub use std::collections::HashMap;
-        pub type Subject = HashMap<String, u8>;
+    test_mod! { result {
+        pub type Subject = core::result::Result<u8, String>;
 
         test_mod!{ from_json_unchecked {
             #[test]
-            fn happy_path() {
-                let result = Subject::from_json_unchecked(json!({
-                    "key": 1 as u8
-                }));
+            fn happy_path_ok() {
+                let result = Subject::from_json_unchecked(json!({"Ok": 8}));
+                assert_eq!(result, Ok(8));
+            }
 
-                assert!(result.contains_key("key".into()));
-                assert_eq!(result.get("key".into()), Some(&1));
+            #[test]
+            fn happy_path_err() {
+                let result = Subject::from_json_unchecked(json!({"Err": "Broken"}));
+                assert_eq!(result, Err("Broken".into()));
             }
 
             #[test]
             #[should_panic]
             fn incorrect_json_type() {
-                Subject::from_json_unchecked(json!([]));
+                let _ = Subject::from_json_unchecked(json!([]));
+            }
+
+            #[test]
+            #[should_panic]
+            fn unknown_key() {
+                let _ = Subject::from_json_unchecked(json!({"Maybe": 8}));
             }
         }}
 
         test_mod!{ to_json {
             #[test]
-            fn happy_path() {
-                let mut subject: Subject = Subject::new();
-                subject.insert("key".into(), 1);
-
-                let json = subject.to_json();
+            fn happy_path_ok() {
+                let subject: Subject = Ok(8);
+                assert_eq!(subject.to_json(), json!({"Ok": 8}));
+            }
 
-                assert_eq!(json, json!({"key": 1}));
+            #[test]
+            fn happy_path_err() {
+                let subject: Subject = Err("Broken".into());
+                assert_eq!(subject.to_json(), json!({"Err": "Broken"}));
             }
         }}
 
         test_mod!{ validate_json {
             #[test]
             fn happy_path() {
-                let result = Subject::validate_json(&json!({
-                    "key": 1 as u8
-                }));
-                assert!(result.is_ok());
+                assert!(Subject::validate_json(&json!({"Ok": 8})).is_ok());
+                assert!(Subject::validate_json(&json!({"Err": "Broken"})).is_ok());
             }
 
             #[test]
             fn incorrect_json_type() {
-                let result = Subject::validate_json(&json!([]));
+                let result = Subject::validate_json(&json!("Ok"));
+                assert_eq!(result, Err(JsonableError::IncompatibleJsonType { got: "string", expected: "object" }));
+            }
 
-                match result {
-                    Err(err) => {
-                        assert_eq!(err, JsonableError::IncompatibleJsonType { got: "array", expected: "object" })
-                    },
-                    _ => assert!(false)
-                };
+            #[test]
+            fn incorrect_key_count() {
+                let result = Subject::validate_json(&json!({"Ok": 8, "Err": "Broken"}));
+                assert_eq!(result, Err(JsonableError::IncorrectObjectKeyCountForEnum { ty: std::any::type_name::<Subject>(), count: 2 }));
+            }
+
+            #[test]
+            fn unknown_key() {
+                let result = Subject::validate_json(&json!({"Maybe": 8}));
+                assert_eq!(result, Err(JsonableError::IncorrectKeyForEnum { ty: std::any::type_name::<Subject>(), key: "Maybe".into(), expected: vec!["Ok", "Err"] }));
             }
         }}
     }}
 
-    test_mod! {hash_set {
-        pub use std::collections::HashSet;
-        pub type Subject = HashSet<String>;
+    test_mod! { rfc3339_timestamp {
+        pub use crate::rfc3339 as subject;
+
+        #[test]
+        fn round_trip() {
+            let time = UNIX_EPOCH + Duration::new(1_700_000_000, 500_000_000);
+            assert_eq!(subject::to_json(&time), json!("2023-11-14T22:13:20.5Z"));
+            assert_eq!(subject::from_json_unchecked(subject::to_json(&time)), time);
+            assert_eq!(subject::to_json(&UNIX_EPOCH), json!("1970-01-01T00:00:00Z"));
+        }
+
+        #[test]
+        fn before_epoch() {
+            let time = UNIX_EPOCH - Duration::new(86_401, 250_000_000);
+            assert_eq!(subject::to_json(&time), json!("1969-12-30T23:59:58.75Z"));
+            assert_eq!(subject::from_json_unchecked(subject::to_json(&time)), time);
+            assert_eq!(
+                subject::from_json_unchecked(json!("0000-03-01T00:00:00Z")),
+                UNIX_EPOCH - Duration::from_secs(62_162_035_200)
+            );
+        }
+
+        #[test]
+        fn offsets() {
+            let time = UNIX_EPOCH + Duration::from_secs(3600);
+            assert_eq!(subject::from_json_unchecked(json!("1970-01-01T02:00:00+01:00")), time);
+            assert_eq!(subject::from_json_unchecked(json!("1969-12-31t23:30:00-01:30")), time);
+            assert_eq!(subject::from_json_unchecked(json!("1970-01-01 01:00:00z")), time);
+        }
+
+        #[test]
+        fn invalid_format() {
+            for value in [
+                "2024-01-01",
+                "2024-01-01T00:00:00",
+                "2024-13-01T00:00:00Z",
+                "2023-02-29T00:00:00Z",
+                "2024-01-01T24:00:00Z",
+                "2024-01-01T23:59:60Z",
+                "2024-01-01T00:00:00.Z",
+                "2024-01-01T00:00:00.1234567891Z",
+                "2024-01-01T00:00:00+0100",
+                "+024-01-01T00:00:00Z",
+                "2024-01-01T00:00:00Zé",
+            ] {
+                assert_eq!(
+                    subject::validate_json(&json!(value)),
+                    Err(JsonableError::InvalidFormat { expected: "RFC 3339 timestamp", got: value.into() })
+                );
+            }
+            assert_eq!(subject::validate_json(&json!("2024-02-29T00:00:00Z")), Ok(()));
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(subject::validate_json(&json!(0)), Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
+            assert_eq!(subject::validate_json(&Value::Null), Err(JsonableError::IncompatibleJsonType { got: "null", expected: "string" }));
+        }
+
+        #[test]
+        fn json_schema() {
+            assert_eq!(subject::json_schema(), json!({ "type": "string", "format": "date-time" }));
+        }
+    }}
+
+    test_mod! { schema_map {
+        pub fn subject() -> SchemaMap {
+            SchemaMap::new()
+                .field::<u32>("port")
+                .field::<String>("host")
+                .field::<Option<bool>>("secure")
+        }
+
+        #[test]
+        fn happy_path() {
+            assert!(subject().validate(&json!({ "port": 8080, "host": "localhost", "secure": true })).is_ok());
+            assert!(subject().validate(&json!({ "port": 8080, "host": "localhost", "extra": [] })).is_ok());
+        }
+
+        #[test]
+        fn invalid_value() {
+            assert_eq!(subject().validate(&json!({ "port": "8080", "host": "localhost" })), Err(JsonableError::InvalidMapValue {
+                key: "port".into(),
+                error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            }));
+        }
+
+        #[test]
+        fn missing_key() {
+            assert_eq!(subject().validate(&json!({ "port": 8080 })), Err(JsonableError::MissingRequiredKey { key: "host".into() }));
+        }
+
+        #[test]
+        fn null_value() {
+            assert_eq!(subject().validate(&json!({ "port": 8080, "host": null })), Err(JsonableError::InvalidMapValue {
+                key: "host".into(),
+                error: Box::from(JsonableError::IncompatibleJsonType { got: "null", expected: "string" })
+            }));
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(subject().validate(&json!([])), Err(JsonableError::IncompatibleJsonType { got: "array", expected: "object" }));
+        }
+    }}
+
+    test_mod! { shared_pointer {
+        #[test]
+        fn rc() {
+            let subject = std::rc::Rc::new(String::from("shared"));
+            assert_eq!(subject.to_json(), json!("shared"));
+            assert_eq!(std::rc::Rc::<String>::from_json(subject.to_json()), Ok(subject));
+            assert!(std::rc::Rc::<String>::validate_json(&json!(1)).is_err());
+        }
+
+        #[test]
+        fn arc() {
+            let subject = std::sync::Arc::new(String::from("shared"));
+            assert_eq!(subject.to_json(), json!("shared"));
+            assert_eq!(std::sync::Arc::<String>::from_json(subject.to_json()), Ok(subject));
+            assert!(std::sync::Arc::<String>::validate_json(&json!(1)).is_err());
+        }
+
+        #[test]
+        fn sharing_is_not_preserved() {
+            let shared = std::rc::Rc::new(String::from("shared"));
+            let subject = vec![shared.clone(), shared];
+            let result = Vec::<std::rc::Rc<String>>::from_json(subject.to_json()).unwrap();
+
+            assert_eq!(result, subject);
+            assert!(!std::rc::Rc::ptr_eq(&result[0], &result[1]));
+        }
+    }}
+
+    test_mod! { string {
+        pub type Subject = String;
 
         test_mod!{ from_json_unchecked {
             #[test]
             fn happy_path() {
-                let values: Vec<String> = vec!["Value 1".into(), "Value 2".into()];
-                let json = Value::Array(values.clone().into_iter().map(|value| Value::String(value)).collect::<Vec<_>>());
-                let subject = Subject::from_json_unchecked(json);
-
-                assert_eq!(subject.len(), values.len());
-                for value in values.iter() {
-                    assert!(subject.contains(value));
-                }
+                let result: Subject = Subject::from_json_unchecked(json!("Uh huh"));
+                assert_eq!(result, Subject::from("Uh huh"));
             }
 
             #[test]
@@ -654,28 +3663,17 @@ pub mod tests {
         test_mod!{ to_json {
             #[test]
             fn happy_path() {
-                let mut subject = Subject::new();
-                subject.insert("Hello".into());
-                subject.insert("World".into());
-
+                let subject = Subject::from("This is a triumph; huge success.");
                 let json = subject.to_json();
 
-                // HashSet does not return keys in a consistent order
-                // Assertions must not depend on order
-                assert!(json.is_array());
-                let vec = json.as_array().unwrap();
-                assert!(vec.contains(&json!("Hello")));
-                assert!(vec.contains(&json!("World")));
+                assert_eq!(json, json!("This is a triumph; huge success."));
             }
         }}
 
         test_mod!{ validate_json {
             #[test]
             fn happy_path() {
-                let values: Vec<String> = vec!["Value 1".into(), "Value 2".into()];
-                let json = Value::Array(values.clone().into_iter().map(|value| Value::String(value)).collect::<Vec<_>>());
-
-                assert!(Subject::validate_json(&json).is_ok());
+                assert!(Subject::validate_json(&json!("I'm a string")).is_ok());
             }
 
             #[test]
@@ -683,68 +3681,174 @@ pub mod tests {
                 let result = Subject::validate_json(&json!({}));
 
                 match result {
-                    Err(err) => {
-                        assert_eq!(err, JsonableError::IncompatibleJsonType { got: "object", expected: "array" })
-                    },
+                    Err(err) => assert_eq!(err, JsonableError::IncompatibleJsonType { got: "object", expected: "string" }),
                     _ => assert!(false)
                 };
             }
         }}
     }}
 
-    test_mod! {option {
-        pub type Subject = Option<u8>;
+    test_mod! { system_time {
+        pub type Subject = SystemTime;
+
+        #[test]
+        fn round_trip() {
+            let subject = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+            assert_eq!(subject.to_json(), json!(1_700_000_000_123u64));
+            assert_eq!(Subject::from_json(subject.to_json()), Ok(subject));
+            assert_eq!(Subject::from_json(json!(0)), Ok(UNIX_EPOCH));
+        }
+
+        #[test]
+        fn truncates_to_millis() {
+            let subject = UNIX_EPOCH + Duration::new(1, 999_999);
+            assert_eq!(subject.to_json(), json!(1000));
+        }
+
+        #[test]
+        fn before_epoch() {
+            assert_eq!(
+                Subject::validate_json(&json!(-1)),
+                Err(JsonableError::NumberOutOfRange { ty: std::any::type_name::<SystemTime>(), value: Number::from(-1) })
+            );
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(Subject::validate_json(&json!("2024-01-01T00:00:00Z")), Err(JsonableError::IncompatibleJsonType { got: "string", expected: "number" }));
+            assert_eq!(Subject::validate_json(&json!(1.5)), Err(JsonableError::IncompatibleJsonType { got: "float", expected: "integer" }));
+        }
+    }}
+
+    test_mod! { tuple {
+        pub type Subject = (String, u32);
+
+        #[test]
+        fn round_trip() {
+            let subject: Subject = ("pair".into(), 2);
+            assert_eq!(subject.to_json(), json!(["pair", 2]));
+            assert_eq!(Subject::from_json(subject.to_json()), Ok(subject));
+        }
+
+        #[test]
+        fn twelve_elements() {
+            let subject = (1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, "twelve".to_string());
+            assert_eq!(<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, String)>::from_json(subject.to_json()), Ok(subject));
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_json_unchecked_wrong_length() {
+            let _ = Subject::from_json_unchecked(json!(["pair"]));
+        }
+
+        #[test]
+        fn incorrect_length() {
+            assert_eq!(Subject::validate_json(&json!(["pair", 2, 3])), Err(JsonableError::InvalidArrayLength { got: 3, expected: 2 }));
+        }
+
+        #[test]
+        fn incorrect_element() {
+            assert_eq!(
+                Subject::validate_json(&json!(["pair", "two"])),
+                Err(JsonableError::IncompatibleEntryAt { index: 1, error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" }) })
+            );
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(Subject::validate_json(&json!({})), Err(JsonableError::IncompatibleJsonType { got: "object", expected: "array" }));
+        }
+    }}
+
+    test_mod! { unit {
+        pub type Subject = ();
 
         test_mod!{ from_json_unchecked {
             #[test]
             fn happy_path() {
-                let result = Subject::from_json_unchecked(json!(8));
-                assert_eq!(result, Some(8 as u8));
+                Subject::from_json_unchecked(json!(null));
             }
+
             #[test]
-            fn happy_path_null() {
-                let result = Subject::from_json_unchecked(json!(null));
-                assert_eq!(result, None);
+            #[should_panic]
+            fn incorrect_json_type() {
+                Subject::from_json_unchecked(json!({}));
             }
         }}
 
         test_mod!{ to_json {
             #[test]
             fn happy_path() {
-                let subject: Subject = Some(8);
-                let result = subject.to_json();
-                assert_eq!(result, json!(8));
+                assert_eq!(().to_json(), json!(null));
+                assert_eq!(Subject::from_json(().to_json()), Ok(()));
             }
 
             #[test]
-            fn happy_path_null() {
-                let subject: Subject = None;
-                let result = subject.to_json();
-                assert_eq!(result, json!(null));
+            fn option() {
+                assert_eq!(Some(()).to_json(), json!(null));
+                assert_eq!(Option::<()>::from_json(json!(null)), Ok(None));
             }
         }}
 
         test_mod!{ validate_json {
             #[test]
             fn happy_path() {
-                assert!(Subject::validate_json(&json!(8)).is_ok());
+                assert!(Subject::validate_json(&json!(null)).is_ok());
             }
 
             #[test]
-            fn happy_path_null() {
-                assert!(Subject::validate_json(&json!(null)).is_ok());
+            fn incorrect_json_type() {
+                let result = Subject::validate_json(&json!({}));
+                assert_eq!(result, Err(JsonableError::IncompatibleJsonType { got: "object", expected: "null" }));
             }
         }}
     }}
 
-    test_mod! { string {
-        pub type Subject = String;
+    #[cfg(feature = "uuid")]
+    test_mod! { uuid {
+        pub type Subject = ::uuid::Uuid;
+
+        #[test]
+        fn round_trip() {
+            let subject = Subject::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+            assert_eq!(subject.get_version_num(), 4);
+            assert_eq!(subject.to_json(), json!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+            assert_eq!(Subject::from_json(subject.to_json()), Ok(subject));
+            assert_eq!(Subject::from_json(json!("67E55044-10B1-426F-9247-BB680E5FE0C8")), Ok(subject));
+        }
+
+        #[test]
+        fn invalid() {
+            assert_eq!(
+                Subject::validate_json(&json!("not-a-uuid")),
+                Err(JsonableError::InvalidFormat { expected: "UUID", got: "not-a-uuid".into() })
+            );
+            assert_eq!(Subject::validate_json(&json!(4)), Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
+        }
+    }}
+
+    test_mod! { vec {
+        pub type Subject = Vec<u8>;
 
         test_mod!{ from_json_unchecked {
             #[test]
             fn happy_path() {
-                let result: Subject = Subject::from_json_unchecked(json!("Uh huh"));
-                assert_eq!(result, Subject::from("Uh huh"));
+                let subject = Subject::from_json_unchecked(json!([1, 2, 3, 4]));
+
+                assert_eq!(subject, vec![1, 2, 3, 4]);
+            }
+
+            #[test]
+            fn presized() {
+                let json = Value::Array((0..10_000).map(|entry| json!(entry % 256)).collect());
+
+                let subject = Subject::from_json_unchecked(json.clone());
+                assert_eq!(subject.len(), 10_000);
+                assert_eq!(subject.capacity(), 10_000);
+
+                let subject = Subject::from_json_fused(json).unwrap();
+                assert_eq!(subject.capacity(), 10_000);
             }
 
             #[test]
@@ -757,72 +3861,147 @@ pub mod tests {
         test_mod!{ to_json {
             #[test]
             fn happy_path() {
-                let subject = Subject::from("This is a triumph; huge success.");
+                let subject: Subject = vec![1, 2, 3, 4];
                 let json = subject.to_json();
 
-                assert_eq!(json, json!("This is a triumph; huge success."));
+                assert_eq!(json, json!([1, 2, 3, 4]));
+            }
+        }}
+
+        test_mod!{ to_json_writer {
+            #[test]
+            fn happy_path() {
+                let subject: Vec<u32> = (0..100_000).collect();
+                let mut buffer: Vec<u8> = Vec::new();
+                subject.to_json_writer(&mut buffer).unwrap();
+
+                let json: Value = from_slice(&buffer).unwrap();
+                assert_eq!(Vec::<u32>::from_json(json), Ok(subject));
+            }
+
+            #[test]
+            fn empty() {
+                let subject: Subject = vec![];
+                let mut buffer: Vec<u8> = Vec::new();
+                subject.to_json_writer(&mut buffer).unwrap();
+
+                assert_eq!(buffer, b"[]");
+            }
+
+            #[test]
+            fn nested() {
+                let subject: Vec<Vec<u8>> = vec![vec![1, 2], vec![], vec![3]];
+                let mut buffer: Vec<u8> = Vec::new();
+                subject.to_json_writer(&mut buffer).unwrap();
+
+                assert_eq!(String::from_utf8(buffer).unwrap(), "[[1,2],[],[3]]");
             }
         }}
 
         test_mod!{ validate_json {
             #[test]
             fn happy_path() {
-                assert!(Subject::validate_json(&json!("I'm a string")).is_ok());
+                assert!(Subject::validate_json(&json!([1])).is_ok());
             }
 
             #[test]
             fn incorrect_json_type() {
                 let result = Subject::validate_json(&json!({}));
-
                 match result {
-                    Err(err) => assert_eq!(err, JsonableError::IncompatibleJsonType { got: "object", expected: "string" }),
+                    Err(err) => assert_eq!(err, JsonableError::IncompatibleJsonType { got: "object", expected: "array" }),
                     _ => assert!(false)
                 };
             }
+
+            #[test]
+            fn incorrect_entry() {
+                assert_eq!(Subject::validate_json(&json!([1, 2, "three"])), Err(JsonableError::IncompatibleEntryAt {
+                    index: 2,
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" }),
+                }));
+            }
+
+            #[test]
+            fn nested_entry() {
+                assert_eq!(Vec::<Vec<u8>>::validate_json(&json!([[1], [], [2, 300]])), Err(JsonableError::IncompatibleEntryAt {
+                    index: 2,
+                    error: Box::from(JsonableError::IncompatibleEntryAt {
+                        index: 1,
+                        error: Box::from(JsonableError::NumberOutOfRange { ty: "u8", value: Number::from(300) }),
+                    }),
+                }));
+            }
         }}
     }}
 
-    test_mod! { vec {
-        pub type Subject = Vec<u8>;
+    test_mod! { vec_deque {
+        pub use std::collections::VecDeque;
+        pub type Subject = VecDeque<u8>;
+
+        #[test]
+        fn round_trip() {
+            let mut subject = Subject::new();
+            subject.push_back(2);
+            subject.push_back(3);
+            subject.push_front(1);
+
+            assert_eq!(subject.to_json(), json!([1, 2, 3]));
+            assert_eq!(Subject::from_json(subject.to_json()), Ok(subject));
+        }
+
+        #[test]
+        fn incorrect_json_type() {
+            assert_eq!(Subject::validate_json(&json!({})), Err(JsonableError::IncompatibleJsonType { got: "object", expected: "array" }));
+        }
+    }}
+
+    test_mod! { wide_number {
+        #[test]
+        fn round_trip_above_u64() {
+            let value = u64::MAX as u128 + 1;
+            assert_eq!(u128::from_json(value.to_json()), Ok(value));
+            assert_eq!(u128::from_json(u128::MAX.to_json()), Ok(u128::MAX));
+        }
 
         test_mod!{ from_json_unchecked {
             #[test]
             fn happy_path() {
-                let subject = Subject::from_json_unchecked(json!([1, 2, 3, 4]));
-
-                assert_eq!(subject, vec![1, 2, 3, 4]);
+                assert_eq!(u128::from_json_unchecked(json!("340282366920938463463374607431768211455")), u128::MAX);
+                assert_eq!(i128::from_json_unchecked(json!("-170141183460469231731687303715884105728")), i128::MIN);
             }
 
             #[test]
             #[should_panic]
             fn incorrect_json_type() {
-                Subject::from_json_unchecked(json!({}));
+                u128::from_json_unchecked(json!(1));
             }
         }}
 
         test_mod!{ to_json {
             #[test]
             fn happy_path() {
-                let subject: Subject = vec![1, 2, 3, 4];
-                let json = subject.to_json();
-
-                assert_eq!(json, json!([1, 2, 3, 4]));
+                assert_eq!(u128::MAX.to_json(), json!("340282366920938463463374607431768211455"));
+                assert_eq!((-5i128).to_json(), json!("-5"));
             }
         }}
 
         test_mod!{ validate_json {
             #[test]
-            fn happy_path() {
-                assert!(Subject::validate_json(&json!([1])).is_ok());
+            fn negative_u128() {
+                let result = u128::validate_json(&json!("-1"));
+                assert_eq!(result, Err(JsonableError::InvalidFormat { expected: "u128 decimal string", got: "-1".into() }));
+            }
+
+            #[test]
+            fn non_numeric() {
+                let result = i128::validate_json(&json!("twelve"));
+                assert_eq!(result, Err(JsonableError::InvalidFormat { expected: "i128 decimal string", got: "twelve".into() }));
             }
 
             #[test]
             fn incorrect_json_type() {
-                let result = Subject::validate_json(&json!({}));
-                match result {
-                    Err(err) => assert_eq!(err, JsonableError::IncompatibleJsonType { got: "object", expected: "array" }),
-                    _ => assert!(false)
-                };
+                let result = i128::validate_json(&json!(12));
+                assert_eq!(result, Err(JsonableError::IncompatibleJsonType { got: "number", expected: "string" }));
             }
         }}
     }}
@@ -0,0 +1,54 @@
+//! `HashMap` fields with case-insensitive keys, selected with `#[jsonable(lowercase_keys)]`.
+//!
+//! Every key is lowercased when read, so `{"Host": a, "PORT": b}` reads as `host` and `port`.
+//! Two keys that only differ by case, such as `"Host"` and `"host"`, are rejected rather than one
+//! silently replacing the other. Keys are written back as stored.
+
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use serde_json::Value;
+
+use crate::{Jsonable, JsonableError, Result};
+
+/// Codec for a `HashMap<String, V>` field that lowercases its keys.
+pub struct LowercaseKeys<M>(PhantomData<M>);
+
+impl<V: Jsonable> LowercaseKeys<HashMap<String, V>> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an object
+    pub fn from_json_unchecked(json: Value) -> HashMap<String, V> {
+        match json {
+            Value::Object(map) => map
+                .into_iter()
+                .map(|(key, value)| (key.to_lowercase(), V::from_json_unchecked(value)))
+                .collect(),
+            _ => panic!("Tried converting non-object json to HashMap"),
+        }
+    }
+
+    pub fn to_json(value: &HashMap<String, V>) -> Value {
+        value.to_json()
+    }
+
+    pub fn json_schema() -> Value {
+        HashMap::<String, V>::json_schema()
+    }
+
+    /// Returns Err([JsonableError::DuplicateKey]) with the lowercased key if two keys only differ by case.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        HashMap::<String, V>::validate_json(json)?;
+
+        if let Value::Object(map) = json {
+            let mut seen = HashSet::with_capacity(map.len());
+            for key in map.keys() {
+                let key = key.to_lowercase();
+                if seen.contains(&key) {
+                    return Err(JsonableError::DuplicateKey { key });
+                }
+                seen.insert(key);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+//! Maps stored as json objects, each key converted through [JsonMapKey].
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use serde_json::{Map, Value};
+
+use crate::{schema, JsonMapKey, Jsonable, JsonableError, Result};
+
+impl<I, T> Jsonable for HashMap<I, T>
+where
+    I: JsonMapKey + Hash + Eq,
+    T: Jsonable,
+{
+    fn from_json_unchecked(json: Value) -> Self {
+        let obj = match json {
+            Value::Object(obj) => obj,
+            _ => panic!("Tried converting non-object json to HashMap"),
+        };
+        let mut map = HashMap::with_capacity(obj.len());
+        for (key, value) in obj {
+            map.insert(I::from_key(key), T::from_json_unchecked(value));
+        }
+
+        map
+    }
+
+    fn from_json_fused(json: Value) -> Result<Self> {
+        map_from_json_fused(json)
+    }
+
+    fn to_json(&self) -> Value {
+        let mut obj = Map::with_capacity(self.keys().len());
+        for (key, value) in self {
+            obj.insert(key.to_key(), value.to_json());
+        }
+
+        Value::Object(obj)
+    }
+
+    fn into_json(self) -> Value {
+        Value::Object(
+            self.into_iter()
+                .map(|(key, value)| (key.into_key(), value.into_json()))
+                .collect(),
+        )
+    }
+
+    /// Streams each `"key":value` pair into `writer` without building the whole object as a [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html).
+    fn to_json_writer<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"{")?;
+        for (index, (key, value)) in self.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut *writer, &key.to_key())?;
+            writer.write_all(b":")?;
+            value.to_json_writer(writer)?;
+        }
+        writer.write_all(b"}")
+    }
+
+    /// Returns Err([JsonableError::InvalidMapValue]) with the key of the first value that cannot be converted to T.
+    ///
+    /// Returns Err([JsonableError::IncompatibleJsonType]) if the json value is not an object.
+    fn validate_json(json: &Value) -> Result<()> {
+        validate_map::<T>(json)
+    }
+
+    /// Returns an [JsonableError::InvalidMapValue] for every value that cannot be converted to T.
+    ///
+    /// Errors are sorted by key so the output does not depend on the json object's key order.
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        validate_map_all::<T>(json)
+    }
+
+    fn json_schema() -> Value {
+        schema::definition(None, || schema::map(T::json_schema()))
+    }
+}
+
+/// Keys are written in sorted order, so equal maps always produce the same json.
+impl<K, T> Jsonable for BTreeMap<K, T>
+where
+    K: JsonMapKey + Ord,
+    T: Jsonable,
+{
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not an object
+    fn from_json_unchecked(json: Value) -> Self {
+        match json {
+            Value::Object(obj) => obj
+                .into_iter()
+                .map(|(key, value)| (K::from_key(key), T::from_json_unchecked(value)))
+                .collect(),
+            _ => panic!("Tried converting non-object json to BTreeMap"),
+        }
+    }
+
+    fn from_json_fused(json: Value) -> Result<Self> {
+        map_from_json_fused(json)
+    }
+
+    fn to_json(&self) -> Value {
+        let mut obj = Map::new();
+        for (key, value) in self {
+            obj.insert(key.to_key(), value.to_json());
+        }
+
+        Value::Object(obj)
+    }
+
+    fn into_json(self) -> Value {
+        Value::Object(
+            self.into_iter()
+                .map(|(key, value)| (key.into_key(), value.into_json()))
+                .collect(),
+        )
+    }
+
+    /// Returns Err([JsonableError::InvalidMapValue]) with the key of the first value that cannot be converted to T.
+    ///
+    /// Returns Err([JsonableError::IncompatibleJsonType]) if the json value is not an object.
+    fn validate_json(json: &Value) -> Result<()> {
+        validate_map::<T>(json)
+    }
+
+    fn validate_json_all(json: &Value) -> Vec<JsonableError> {
+        validate_map_all::<T>(json)
+    }
+
+    fn json_schema() -> Value {
+        schema::definition(None, || schema::map(T::json_schema()))
+    }
+}
+
+/// Shared `from_json_fused` of the map impls.
+pub(crate) fn map_from_json_fused<K, T, M>(json: Value) -> Result<M>
+where
+    K: JsonMapKey,
+    T: Jsonable,
+    M: FromIterator<(K, T)>,
+{
+    match json {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| match T::from_json_fused(value) {
+                Ok(value) => Ok((K::from_key(key), value)),
+                Err(err) => Err(JsonableError::InvalidMapValue {
+                    key,
+                    error: Box::from(err),
+                }),
+            })
+            .collect(),
+        // Validation always fails for anything other than an object
+        json => validate_map::<T>(&json).map(|_| unreachable!()),
+    }
+}
+
+/// Shared `validate_json` of the map impls.
+///
+/// Returns Err([JsonableError::InvalidMapValue]) with the key of the first value that cannot be converted to T.
+///
+/// Returns Err([JsonableError::IncompatibleJsonType]) if the json value is not an object.
+pub(crate) fn validate_map<T: Jsonable>(json: &Value) -> Result<()> {
+    match json {
+        Value::Object(map) => {
+            for (key, value) in map {
+                T::validate_json(value).map_err(|err| JsonableError::InvalidMapValue {
+                    key: key.clone(),
+                    error: Box::from(err),
+                })?;
+            }
+
+            Ok(())
+        }
+        Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "array",
+            expected: "object",
+        }),
+        Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "bool",
+            expected: "object",
+        }),
+        Value::Null => Err(JsonableError::IncompatibleJsonType {
+            got: "null",
+            expected: "object",
+        }),
+        Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "number",
+            expected: "object",
+        }),
+        Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "string",
+            expected: "object",
+        }),
+    }
+}
+
+/// Shared `validate_json_all` of the map impls.
+///
+/// Returns an [JsonableError::InvalidMapValue] for every value that cannot be converted to T.
+///
+/// Errors are sorted by key so the output does not depend on the json object's key order.
+pub(crate) fn validate_map_all<T: Jsonable>(json: &Value) -> Vec<JsonableError> {
+    match json {
+        Value::Object(map) => {
+            let mut errors: Vec<(&String, JsonableError)> = map
+                .iter()
+                .filter_map(|(key, value)| T::validate_json(value).err().map(|err| (key, err)))
+                .collect();
+            errors.sort_by_key(|(key, _)| *key);
+
+            errors
+                .into_iter()
+                .map(|(key, err)| JsonableError::InvalidMapValue {
+                    key: key.clone(),
+                    error: Box::from(err),
+                })
+                .collect()
+        }
+        _ => validate_map::<T>(json).err().into_iter().collect(),
+    }
+}
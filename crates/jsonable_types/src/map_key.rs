@@ -0,0 +1,53 @@
+/// A type that can be used as the key of a json object, such as the keys of a [HashMap](std::collections::HashMap).
+///
+/// Implemented for every key convertible to and from [String], such as `String`, `Box<str>`,
+/// `Cow<str>` and newtypes implementing `From<String>` with `String: From<Key>`. Keys that are not
+/// strings, such as a newtype around an integer, can implement it directly:
+///
+/// ```ignore
+/// impl JsonMapKey for UserId {
+///     fn from_key(key: String) -> Self {
+///         UserId(key.parse().unwrap())
+///     }
+///
+///     fn to_key(&self) -> String {
+///         self.0.to_string()
+///     }
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a json map key",
+    label = "map keys must implement JsonMapKey or be String-convertible",
+    note = "json object keys are always strings; a key type must convert from and into `String`, or implement `JsonMapKey`"
+)]
+pub trait JsonMapKey: Sized {
+    fn from_key(key: String) -> Self;
+
+    fn to_key(&self) -> String;
+
+    /// Consumes the key, moving out its string where it owns one.
+    fn into_key(self) -> String {
+        self.to_key()
+    }
+}
+
+// Not recommended in diagnostics, so a missing key bound reports `JsonMapKey` rather than
+// the `From` conversions behind it
+#[diagnostic::do_not_recommend]
+impl<K> JsonMapKey for K
+where
+    K: From<String> + Clone,
+    String: From<K>,
+{
+    fn from_key(key: String) -> Self {
+        K::from(key)
+    }
+
+    fn to_key(&self) -> String {
+        String::from(self.clone())
+    }
+
+    fn into_key(self) -> String {
+        String::from(self)
+    }
+}
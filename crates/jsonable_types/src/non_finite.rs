@@ -0,0 +1,38 @@
+//! Json numbers cannot hold `NaN` or the infinities, so `f32` and `f64` store them as the
+//! strings `"NaN"`, `"Infinity"`, and `"-Infinity"`, and read those strings back. Finite values
+//! stay plain numbers.
+
+use serde_json::{json, Value};
+
+pub const EXPECTED: &str = "\"NaN\", \"Infinity\", or \"-Infinity\"";
+
+pub fn parse(value: &str) -> Option<f64> {
+    match value {
+        "NaN" => Some(f64::NAN),
+        "Infinity" => Some(f64::INFINITY),
+        "-Infinity" => Some(f64::NEG_INFINITY),
+        _ => None,
+    }
+}
+
+pub fn to_json(value: f64) -> Value {
+    if value.is_nan() {
+        Value::String("NaN".into())
+    } else if value == f64::INFINITY {
+        Value::String("Infinity".into())
+    } else if value == f64::NEG_INFINITY {
+        Value::String("-Infinity".into())
+    } else {
+        Value::from(value)
+    }
+}
+
+/// Schema of a number or one of the non-finite strings.
+pub fn json_schema() -> Value {
+    json!({
+        "anyOf": [
+            { "type": "number" },
+            { "enum": ["NaN", "Infinity", "-Infinity"] }
+        ]
+    })
+}
@@ -0,0 +1,37 @@
+//! `Vec` fields that read `null` as an empty vec, selected with `#[jsonable(null_as_empty)]`.
+//!
+//! `null` or a missing key reads as `vec![]`, anything else is read as a `Vec<T>`. The vec is
+//! always written as an array, so an empty vec is written as `[]` rather than `null`.
+
+use std::marker::PhantomData;
+
+use serde_json::Value;
+
+use crate::{schema, Jsonable, Result};
+
+/// Codec for a `Vec<T>` field that treats `null` as empty.
+pub struct NullAsEmpty<V>(PhantomData<V>);
+
+impl<T: Jsonable> NullAsEmpty<Vec<T>> {
+    pub fn from_json_unchecked(json: Value) -> Vec<T> {
+        match json {
+            Value::Null => Vec::new(),
+            json => Vec::<T>::from_json_unchecked(json),
+        }
+    }
+
+    pub fn to_json(value: &Vec<T>) -> Value {
+        value.to_json()
+    }
+
+    pub fn json_schema() -> Value {
+        schema::nullable(Vec::<T>::json_schema())
+    }
+
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Null => Ok(()),
+            json => Vec::<T>::validate_json(json),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! `Duration` fields that read `null` as zero, selected with `#[jsonable(null_as_zero)]`.
+//!
+//! `null` or a missing key reads as `Duration::ZERO`, anything else is read as a `Duration`.
+//! The duration is always written as usual, so a zero duration is written as
+//! `{ "secs": 0, "nanos": 0 }` rather than `null`.
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::{schema, Jsonable, Result};
+
+/// Codec for a `Duration` field that treats `null` as zero.
+pub struct NullAsZero<D>(PhantomData<D>);
+
+impl NullAsZero<Duration> {
+    pub fn from_json_unchecked(json: Value) -> Duration {
+        match json {
+            Value::Null => Duration::ZERO,
+            json => Duration::from_json_unchecked(json),
+        }
+    }
+
+    pub fn to_json(value: &Duration) -> Value {
+        value.to_json()
+    }
+
+    pub fn json_schema() -> Value {
+        schema::nullable(Duration::json_schema())
+    }
+
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Null => Ok(()),
+            json => Duration::validate_json(json),
+        }
+    }
+}
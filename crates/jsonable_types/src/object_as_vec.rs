@@ -0,0 +1,90 @@
+//! `Vec` fields that also accept an object keyed by index, selected with `#[jsonable(object_as_vec)]`.
+//!
+//! Arrays are read as usual. Objects such as `{"0": a, "1": b}` have their values collected in
+//! ascending numeric order of their keys, so `{"10": c, "2": b, "1": a}` reads as `[a, b, c]`.
+//! Gaps between keys are ignored. Every key must be a non-negative integer.
+//!
+//! The vec is always written back as an array.
+
+use std::marker::PhantomData;
+
+use serde_json::{json, Map, Value};
+
+use crate::{schema, Jsonable, JsonableError, Result};
+
+const EXPECTED: &str = "non-negative integer key";
+
+/// Codec for a `Vec<T>` field that also accepts an index keyed object.
+pub struct ObjectAsVec<V>(PhantomData<V>);
+
+impl<T: Jsonable> ObjectAsVec<Vec<T>> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is neither an array nor an index keyed object
+    pub fn from_json_unchecked(json: Value) -> Vec<T> {
+        match json {
+            Value::Object(map) => sorted_values(map)
+                .unwrap_or_else(|key| panic!("Tried converting object with key '{}' to Vec", key))
+                .into_iter()
+                .map(|(_, value)| T::from_json_unchecked(value))
+                .collect(),
+            json => Vec::<T>::from_json_unchecked(json),
+        }
+    }
+
+    pub fn to_json(value: &Vec<T>) -> Value {
+        value.to_json()
+    }
+
+    pub fn json_schema() -> Value {
+        schema::any_of(vec![
+            Vec::<T>::json_schema(),
+            json!({ "type": "object", "propertyNames": { "pattern": "^[0-9]+$" }, "additionalProperties": T::json_schema() }),
+        ])
+    }
+
+    /// Returns Err([JsonableError::InvalidFormat]) for a key that is not a non-negative integer.
+    ///
+    /// Returns Err([JsonableError::InvalidMapValue]) with the key of the first value, in index order, that cannot be converted to T.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Object(map) => {
+                let mut entries = Vec::with_capacity(map.len());
+                for (key, value) in map {
+                    match key.parse::<usize>() {
+                        Ok(index) => entries.push((index, key, value)),
+                        Err(_) => {
+                            return Err(JsonableError::InvalidFormat {
+                                expected: EXPECTED,
+                                got: key.clone(),
+                            })
+                        }
+                    }
+                }
+                entries.sort_by_key(|(index, _, _)| *index);
+
+                for (_, key, value) in entries {
+                    T::validate_json(value).map_err(|err| JsonableError::InvalidMapValue {
+                        key: key.clone(),
+                        error: Box::from(err),
+                    })?;
+                }
+
+                Ok(())
+            }
+            json => Vec::<T>::validate_json(json),
+        }
+    }
+}
+
+/// Values of `map` in ascending order of their keys, or the first key that is not an index.
+fn sorted_values(map: Map<String, Value>) -> core::result::Result<Vec<(usize, Value)>, String> {
+    let mut entries = Vec::with_capacity(map.len());
+    for (key, value) in map {
+        match key.parse::<usize>() {
+            Ok(index) => entries.push((index, value)),
+            Err(_) => return Err(key),
+        }
+    }
+    entries.sort_by_key(|(index, _)| *index);
+
+    Ok(entries)
+}
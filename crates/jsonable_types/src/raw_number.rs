@@ -0,0 +1,85 @@
+use std::fmt;
+
+use serde_json::{json, Number, Value};
+
+use crate::{Jsonable, JsonableError, Result};
+
+/// A json number kept exactly as read, without converting it to a Rust integer or float.
+///
+/// Integers up to `u64::MAX` and down to `i64::MIN` always round-trip unchanged, where an `f64`
+/// would already round `12345678901234567890`. With the `arbitrary_precision` feature, which enables
+/// the `serde_json` feature of the same name, any number token is kept digit for digit:
+///
+/// ```ignore
+/// let json: Value = serde_json::from_str("123456789012345678901234567890.5")?;
+/// let number = RawNumber::from_json(json)?;
+/// assert_eq!(number.to_string(), "123456789012345678901234567890.5");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawNumber(pub Number);
+
+impl From<Number> for RawNumber {
+    fn from(number: Number) -> Self {
+        RawNumber(number)
+    }
+}
+
+impl From<RawNumber> for Number {
+    fn from(number: RawNumber) -> Self {
+        number.0
+    }
+}
+
+impl fmt::Display for RawNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Jsonable for RawNumber {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a number
+    fn from_json_unchecked(json: Value) -> Self {
+        match json {
+            Value::Number(number) => RawNumber(number),
+            _ => panic!("Tried converting non-number json to RawNumber"),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Number(self.0.clone())
+    }
+
+    fn into_json(self) -> Value {
+        Value::Number(self.0)
+    }
+
+    fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Number(_) => Ok(()),
+            Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "array",
+                expected: "number",
+            }),
+            Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "bool",
+                expected: "number",
+            }),
+            Value::Null => Err(JsonableError::IncompatibleJsonType {
+                got: "null",
+                expected: "number",
+            }),
+            Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "object",
+                expected: "number",
+            }),
+            Value::String(_) => Err(JsonableError::IncompatibleJsonType {
+                got: "string",
+                expected: "number",
+            }),
+        }
+    }
+
+    fn json_schema() -> Value {
+        json!({ "type": "number" })
+    }
+}
@@ -0,0 +1,39 @@
+//! `Option` fields that must still be present in the json, selected with `#[jsonable(required)]`.
+//!
+//! Absent keys and `null` are rejected, anything else is read as `Some(T)`. Useful when a field is
+//! only optional for convenience on the Rust side. `None` is still written as `null`.
+
+use std::marker::PhantomData;
+
+use serde_json::Value;
+
+use crate::{Jsonable, JsonableError, Result};
+
+/// Codec for an `Option<T>` field that rejects `null`.
+pub struct Required<O>(PhantomData<O>);
+
+impl<T: Jsonable> Required<Option<T>> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is `null`
+    pub fn from_json_unchecked(json: Value) -> Option<T> {
+        match json {
+            Value::Null => panic!("Tried converting null json to a required Option"),
+            json => Some(T::from_json_unchecked(json)),
+        }
+    }
+
+    pub fn to_json(value: &Option<T>) -> Value {
+        value.to_json()
+    }
+
+    pub fn json_schema() -> Value {
+        T::json_schema()
+    }
+
+    /// Returns Err([JsonableError::MissingField]) for `null`.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::Null => Err(JsonableError::MissingField),
+            json => T::validate_json(json),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! [SystemTime](std::time::SystemTime) as an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339)
+//! timestamp string such as `"2024-01-01T12:30:00.5Z"`, selected with
+//! `#[jsonable(with = "jsonable::rfc3339")]`.
+//!
+//! Unlike the default epoch milliseconds, times before the unix epoch can be stored, and
+//! nanosecond precision is kept. Timestamps are written in UTC and read with any offset. Leap
+//! seconds are rejected, since `SystemTime` cannot represent them.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+
+use crate::{JsonableError, Result};
+
+const EXPECTED: &str = "RFC 3339 timestamp";
+
+/// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) is not a valid RFC 3339 timestamp string
+pub fn from_json_unchecked(json: Value) -> SystemTime {
+    let value = json
+        .as_str()
+        .unwrap_or_else(|| panic!("Tried converting non-string json to SystemTime"));
+    parse(value).unwrap_or_else(|| panic!("Invalid RFC 3339 timestamp: {}", value))
+}
+
+/// Panics if `value` is outside of the years 0000 to 9999, which RFC 3339 cannot represent.
+pub fn to_json(value: &SystemTime) -> Value {
+    let (secs, nanos) = match value.duration_since(UNIX_EPOCH) {
+        Ok(since) => (since.as_secs() as i64, since.subsec_nanos()),
+        Err(err) => {
+            let before = err.duration();
+            match before.subsec_nanos() {
+                0 => (-(before.as_secs() as i64), 0),
+                nanos => (-(before.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    };
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    if !(0..=9999).contains(&year) {
+        panic!(
+            "Tried converting SystemTime in year {} to an RFC 3339 timestamp",
+            year
+        );
+    }
+    let time = secs.rem_euclid(86_400);
+    let (hours, minutes, seconds) = (time / 3600, time / 60 % 60, time % 60);
+
+    let mut result = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year, month, day, hours, minutes, seconds
+    );
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+        result.push_str(&format!(".{}", fraction.trim_end_matches('0')));
+    }
+    result.push('Z');
+
+    Value::String(result)
+}
+
+pub fn json_schema() -> Value {
+    json!({ "type": "string", "format": "date-time" })
+}
+
+/// Returns Err([JsonableError::InvalidFormat]) if the string is not a valid RFC 3339 timestamp.
+pub fn validate_json(json: &Value) -> Result<()> {
+    match json {
+        Value::String(value) => match parse(value) {
+            Some(_) => Ok(()),
+            None => Err(JsonableError::InvalidFormat {
+                expected: EXPECTED,
+                got: value.clone(),
+            }),
+        },
+        Value::Array(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "array",
+            expected: "string",
+        }),
+        Value::Bool(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "bool",
+            expected: "string",
+        }),
+        Value::Null => Err(JsonableError::IncompatibleJsonType {
+            got: "null",
+            expected: "string",
+        }),
+        Value::Number(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "number",
+            expected: "string",
+        }),
+        Value::Object(_) => Err(JsonableError::IncompatibleJsonType {
+            got: "object",
+            expected: "string",
+        }),
+    }
+}
+
+fn parse(value: &str) -> Option<SystemTime> {
+    let bytes = value.as_bytes();
+    if !value.is_ascii()
+        || bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    let year = digits(&value[0..4])?;
+    let month = digits(&value[5..7])?;
+    let day = digits(&value[8..10])?;
+    let hours = digits(&value[11..13])?;
+    let minutes = digits(&value[14..16])?;
+    let seconds = digits(&value[17..19])?;
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hours > 23
+        || minutes > 59
+        || seconds > 59
+    {
+        return None;
+    }
+
+    let mut rest = &value[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let end = fraction
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(fraction.len());
+        if end == 0 || end > 9 {
+            return None;
+        }
+        nanos = format!("{:0<9}", &fraction[..end]).parse().ok()?;
+        rest = &fraction[end..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes().first()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            if rest.len() != 6 || rest.as_bytes()[3] != b':' {
+                return None;
+            }
+            let (offset_hours, offset_minutes) = (digits(&rest[1..3])?, digits(&rest[4..6])?);
+            if offset_hours > 23 || offset_minutes > 59 {
+                return None;
+            }
+            sign * (offset_hours * 3600 + offset_minutes * 60)
+        }
+    };
+
+    let secs =
+        days_from_civil(year, month, day) * 86_400 + hours * 3600 + minutes * 60 + seconds - offset;
+    let since_epoch = Duration::from_secs(secs.unsigned_abs());
+    if secs >= 0 {
+        UNIX_EPOCH
+            .checked_add(since_epoch)?
+            .checked_add(Duration::from_nanos(nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(since_epoch)?
+            .checked_add(Duration::from_nanos(nanos))
+    }
+}
+
+/// Value of `text` if it is made of ascii digits only.
+fn digits(text: &str) -> Option<i64> {
+    if text.bytes().all(|c| c.is_ascii_digit()) {
+        text.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [days_from_civil], as `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,323 @@
+//! Building blocks for [Jsonable::json_schema](crate::Jsonable::json_schema), shared by the
+//! provided impls and the derive.
+//!
+//! Schemas follow [JSON Schema 2020-12](https://json-schema.org/draft/2020-12/schema). A nested
+//! type with a [schema_ref_name](crate::Jsonable::schema_ref_name) is described once under the
+//! outermost schema's `$defs` and referenced as `{"$ref": "#/$defs/<name>"}`, which also gives
+//! recursive types a finite schema. Other nested types are inlined.
+
+use std::cell::RefCell;
+
+use serde_json::{json, Map, Value};
+
+thread_local! {
+    /// Definitions collected by the outermost [definition] call running on this thread
+    static DEFINITIONS: RefCell<Option<Definitions>> = const { RefCell::new(None) };
+}
+
+struct Definitions {
+    schemas: Map<String, Value>,
+    root: Option<String>,
+    root_referenced: bool,
+    inline_next: bool,
+}
+
+/// Clears the collected definitions once the outermost schema is built, or its body panicked.
+struct Collecting;
+
+impl Drop for Collecting {
+    fn drop(&mut self) {
+        DEFINITIONS.with(|definitions| definitions.borrow_mut().take());
+    }
+}
+
+/// Schema built by `body` for a type named `name`, as returned by
+/// [schema_ref_name](crate::Jsonable::schema_ref_name).
+///
+/// The outermost call returns `body`'s schema holding every definition collected while building
+/// it under `$defs`. Nested calls with a name return a `$ref` to their definition instead, building
+/// it only the first time, and nested calls without one inline `body`'s schema.
+pub fn definition(name: Option<&str>, body: impl FnOnce() -> Value) -> Value {
+    let collecting = DEFINITIONS.with(|definitions| {
+        definitions
+            .borrow_mut()
+            .as_mut()
+            .map(|definitions| std::mem::take(&mut definitions.inline_next))
+    });
+    let name = match (collecting, name) {
+        (None, _) => return root(name, body),
+        (Some(true), _) | (Some(false), None) => return body(),
+        (Some(false), Some(name)) => name,
+    };
+
+    let known = DEFINITIONS.with(|definitions| {
+        let mut definitions = definitions.borrow_mut();
+        let definitions = definitions
+            .as_mut()
+            .expect("definitions are being collected");
+        if definitions.root.as_deref() == Some(name) {
+            definitions.root_referenced = true;
+            return true;
+        }
+        if definitions.schemas.contains_key(name) {
+            return true;
+        }
+        // Claimed before `body` runs, so a recursive reference stops here
+        definitions.schemas.insert(name.into(), Value::Null);
+        false
+    });
+    if !known {
+        let schema = body();
+        DEFINITIONS.with(|definitions| {
+            if let Some(definitions) = definitions.borrow_mut().as_mut() {
+                definitions.schemas.insert(name.into(), schema);
+            }
+        });
+    }
+
+    json!({ "$ref": format!("#/$defs/{}", name) })
+}
+
+fn root(name: Option<&str>, body: impl FnOnce() -> Value) -> Value {
+    DEFINITIONS.with(|definitions| {
+        *definitions.borrow_mut() = Some(Definitions {
+            schemas: Map::new(),
+            root: name.map(String::from),
+            root_referenced: false,
+            inline_next: false,
+        })
+    });
+    let collecting = Collecting;
+    let mut schema = body();
+    let definitions = DEFINITIONS
+        .with(|definitions| definitions.borrow_mut().take())
+        .expect("definitions are being collected");
+    drop(collecting);
+
+    let mut schemas = definitions.schemas;
+    if let (Some(name), true) = (name, definitions.root_referenced) {
+        schemas.insert(name.into(), schema.clone());
+    }
+    if let (Value::Object(map), false) = (&mut schema, schemas.is_empty()) {
+        map.insert("$defs".into(), Value::Object(schemas));
+    }
+    schema
+}
+
+/// Schema built by `body`, with the outermost type it describes inlined rather than referenced,
+/// as needed to merge its properties into another object.
+pub fn inline(body: impl FnOnce() -> Value) -> Value {
+    let set = |inline: bool| {
+        DEFINITIONS.with(|definitions| {
+            if let Some(definitions) = definitions.borrow_mut().as_mut() {
+                definitions.inline_next = inline;
+            }
+        })
+    };
+    set(true);
+    let schema = body();
+    set(false);
+    schema
+}
+
+/// Schema accepting `schema` or `null`.
+pub fn nullable(schema: Value) -> Value {
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+/// Schema of an array whose entries all match `items`.
+pub fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+/// Schema of an array holding exactly one entry per schema in `entries`, in order.
+pub fn tuple(entries: Vec<Value>) -> Value {
+    let len = entries.len();
+    json!({
+        "type": "array",
+        "prefixItems": entries,
+        "items": false,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+/// Schema of an object whose values all match `values`, whatever their keys.
+pub fn map(values: Value) -> Value {
+    json!({ "type": "object", "additionalProperties": values })
+}
+
+/// Schema matching at least one of `schemas`.
+pub fn any_of(schemas: Vec<Value>) -> Value {
+    json!({ "anyOf": schemas })
+}
+
+/// Schema matching exactly one of `schemas`.
+pub fn one_of(schemas: Vec<Value>) -> Value {
+    json!({ "oneOf": schemas })
+}
+
+/// Schema of a json object with known keys.
+///
+/// ```ignore
+/// let schema = ObjectSchema::new()
+///     .property("name", String::json_schema(), true)
+///     .property("nickname", Option::<String>::json_schema(), false)
+///     .build(false);
+/// ```
+#[derive(Default)]
+pub struct ObjectSchema {
+    properties: Map<String, Value>,
+    required: Vec<Value>,
+}
+
+impl ObjectSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects the value under `key` to match `schema`. A `required` key must be present.
+    pub fn property(mut self, key: &str, schema: Value, required: bool) -> Self {
+        if required {
+            self.required.push(key.into());
+        }
+        self.properties.insert(key.into(), schema);
+        self
+    }
+
+    /// Merges the properties of the object `schema` into this one, each key prefixed with
+    /// `prefix`, as stored by a `#[jsonable(flatten)]` field. Build `schema` with [inline], since
+    /// a `$ref` has no properties to merge.
+    pub fn flatten(mut self, prefix: &str, mut schema: Value) -> Self {
+        if let Some(Value::Array(required)) = schema.get_mut("required").map(Value::take) {
+            for key in required.iter().filter_map(Value::as_str) {
+                self.required.push(format!("{}{}", prefix, key).into());
+            }
+        }
+        if let Some(Value::Object(properties)) = schema.get_mut("properties").map(Value::take) {
+            for (key, property) in properties {
+                self.properties
+                    .insert(format!("{}{}", prefix, key), property);
+            }
+        }
+        self
+    }
+
+    /// Finishes the schema. Keys without a property are rejected when `deny_unknown_fields` is set.
+    pub fn build(self, deny_unknown_fields: bool) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".into(), "object".into());
+        schema.insert("properties".into(), Value::Object(self.properties));
+        if !self.required.is_empty() {
+            schema.insert("required".into(), Value::Array(self.required));
+        }
+        if deny_unknown_fields {
+            schema.insert("additionalProperties".into(), false.into());
+        }
+        Value::Object(schema)
+    }
+}
+
+/// Schema of an enum stored in the default, externally tagged, form: unit variants as their name,
+/// other variants as an object holding their payload under their name. A unit variant's payload
+/// is `None`.
+pub fn externally_tagged(variants: Vec<(&str, Option<Value>)>, null_variant: bool) -> Value {
+    let mut names = Vec::new();
+    let mut schemas = Vec::new();
+    for (name, payload) in variants {
+        match payload {
+            Some(payload) => schemas.push(
+                ObjectSchema::new()
+                    .property(name, payload, true)
+                    .build(true),
+            ),
+            None => {
+                // Also read from an object holding `{}` or `null` under the name
+                let payload = json!({ "type": ["object", "null"], "maxProperties": 0 });
+                schemas.push(
+                    ObjectSchema::new()
+                        .property(name, payload, true)
+                        .build(true),
+                );
+                names.push(Value::from(name));
+            }
+        }
+    }
+    if !names.is_empty() {
+        schemas.insert(0, json!({ "type": "string", "enum": names }));
+    }
+    if null_variant {
+        schemas.push(json!({ "type": "null" }));
+    }
+
+    one_of(schemas)
+}
+
+/// Schema of a `#[jsonable(tag = "..", content = "..")]` enum.
+pub fn adjacently_tagged(
+    tag: &str,
+    content: &str,
+    variants: Vec<(&str, Option<Value>)>,
+    null_variant: bool,
+) -> Value {
+    let mut schemas: Vec<Value> = variants
+        .into_iter()
+        .map(|(name, payload)| {
+            let schema = ObjectSchema::new().property(tag, json!({ "const": name }), true);
+            match payload {
+                Some(payload) => schema.property(content, payload, true),
+                None => schema,
+            }
+            .build(true)
+        })
+        .collect();
+    if null_variant {
+        schemas.push(json!({ "type": "null" }));
+    }
+
+    one_of(schemas)
+}
+
+/// Schema of a `#[jsonable(tag = "..")]` enum. Each payload is the object schema of the
+/// variant's fields, unit variants have none.
+pub fn internally_tagged(
+    tag: &str,
+    variants: Vec<(&str, Option<Value>)>,
+    null_variant: bool,
+) -> Value {
+    let mut schemas: Vec<Value> = variants
+        .into_iter()
+        .map(|(name, payload)| {
+            let mut schema = payload.unwrap_or_else(|| ObjectSchema::new().build(false));
+            if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+                properties.insert(tag.into(), json!({ "const": name }));
+            }
+            match schema.get_mut("required") {
+                Some(Value::Array(required)) => required.insert(0, tag.into()),
+                _ => {
+                    schema["required"] = json!([tag]);
+                }
+            }
+            schema
+        })
+        .collect();
+    if null_variant {
+        schemas.push(json!({ "type": "null" }));
+    }
+
+    one_of(schemas)
+}
+
+/// Nests `schema` under each segment of the JSON pointer `path`, as stored by a
+/// `#[jsonable(tag_path = "..")]` enum.
+pub fn at_path(path: &str, schema: Value) -> Value {
+    path[1..]
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .rev()
+        .fold(schema, |schema, segment| {
+            ObjectSchema::new()
+                .property(&segment, schema, true)
+                .build(false)
+        })
+}
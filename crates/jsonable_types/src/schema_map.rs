@@ -0,0 +1,84 @@
+use serde_json::Value;
+
+use crate::{Jsonable, JsonableError, Result};
+
+type Validator = fn(&Value) -> Result<()>;
+
+/// Validates a json object whose keys each expect their own type, configured at runtime.
+///
+/// ```ignore
+/// let schema = SchemaMap::new().field::<u32>("port").field::<String>("host");
+/// schema.validate(&json!({ "port": 8080, "host": "localhost" }))?;
+/// ```
+///
+/// Each field is checked with its type's [Jsonable::validate_json]. A missing key is checked as
+/// `null`, so only `Option` fields may be left out. Keys without a field are ignored.
+#[derive(Default)]
+pub struct SchemaMap {
+    fields: Vec<(String, Validator)>,
+}
+
+impl SchemaMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects the value under `key` to be convertible to `T`.
+    pub fn field<T: Jsonable>(mut self, key: impl Into<String>) -> Self {
+        self.fields.push((key.into(), T::validate_json));
+        self
+    }
+
+    /// Returns Err([JsonableError::InvalidMapValue]) with the key of the first field, in the order they were added, that cannot be converted,
+    /// or Err([JsonableError::MissingRequiredKey]) if that field's key is absent and its type does not accept `null`.
+    ///
+    /// Returns Err([JsonableError::IncompatibleJsonType]) if the json value is not an object.
+    pub fn validate(&self, json: &Value) -> Result<()> {
+        let map = match json {
+            Value::Object(map) => map,
+            Value::Array(_) => {
+                return Err(JsonableError::IncompatibleJsonType {
+                    got: "array",
+                    expected: "object",
+                })
+            }
+            Value::Bool(_) => {
+                return Err(JsonableError::IncompatibleJsonType {
+                    got: "bool",
+                    expected: "object",
+                })
+            }
+            Value::Null => {
+                return Err(JsonableError::IncompatibleJsonType {
+                    got: "null",
+                    expected: "object",
+                })
+            }
+            Value::Number(_) => {
+                return Err(JsonableError::IncompatibleJsonType {
+                    got: "number",
+                    expected: "object",
+                })
+            }
+            Value::String(_) => {
+                return Err(JsonableError::IncompatibleJsonType {
+                    got: "string",
+                    expected: "object",
+                })
+            }
+        };
+
+        for (key, validate) in &self.fields {
+            match map.get(key) {
+                Some(value) => validate(value).map_err(|err| JsonableError::InvalidMapValue {
+                    key: key.clone(),
+                    error: Box::from(err),
+                })?,
+                None => validate(&Value::Null)
+                    .map_err(|_| JsonableError::MissingRequiredKey { key: key.clone() })?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+//! Fields of types implementing serde's traits instead of [Jsonable], selected with
+//! `#[jsonable(with_serde)]`. Requires the `serde` feature.
+//!
+//! The field is written with `serde_json::to_value` and read with `serde_json::from_value`, so
+//! types that already derive `Serialize` and `Deserialize` can be mixed into derived structs.
+//! Serde reports a single error, so only the first problem with the field is returned.
+
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{JsonableError, Result};
+
+/// Codec for a field of any `Serialize + DeserializeOwned` type.
+pub struct WithSerde<T>(PhantomData<T>);
+
+impl<T: Serialize + DeserializeOwned> WithSerde<T> {
+    /// Panics if the [serde_json::Value](https://docs.serde.rs/serde_json/value/enum.Value.html) cannot be deserialized into T
+    pub fn from_json_unchecked(json: Value) -> T {
+        serde_json::from_value(json).unwrap_or_else(|err| {
+            panic!(
+                "Tried converting incompatible json to {}: {}",
+                std::any::type_name::<T>(),
+                err
+            )
+        })
+    }
+
+    /// Panics if T cannot be serialized as json, such as a map with non-string keys
+    pub fn to_json(value: &T) -> Value {
+        serde_json::to_value(value).unwrap_or_else(|err| {
+            panic!(
+                "Tried converting {} to json: {}",
+                std::any::type_name::<T>(),
+                err
+            )
+        })
+    }
+
+    pub fn json_schema() -> Value {
+        Value::Object(serde_json::Map::new())
+    }
+
+    /// Returns Err([JsonableError::Serde]) with serde's message if the json cannot be deserialized into T.
+    pub fn validate_json(json: &Value) -> Result<()> {
+        T::deserialize(json)
+            .map(|_| ())
+            .map_err(|err| JsonableError::Serde(err.to_string()))
+    }
+}
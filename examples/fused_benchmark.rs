@@ -0,0 +1,68 @@
+//! Compares `from_json`, which validates and then converts, with `from_json_fused`, which does
+//! both in a single walk of the json.
+//!
+//! Run with `cargo run --release --example fused_benchmark`.
+
+use std::time::{Duration, Instant};
+
+use jsonable::*;
+use serde_json::{json, Value};
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Leaf {
+    pub id: u32,
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Branch {
+    pub leaves: Vec<Leaf>,
+    pub children: Vec<Branch>,
+}
+
+const DEPTH: u32 = 6;
+const FANOUT: u32 = 4;
+const ITERATIONS: u32 = 20;
+
+fn tree(depth: u32) -> Value {
+    let leaves: Vec<Value> = (0..FANOUT)
+        .map(|id| json!({ "id": id, "name": format!("leaf {}", id), "tags": ["a", "b", "c"] }))
+        .collect();
+    let children: Vec<Value> = match depth {
+        0 => Vec::new(),
+        _ => (0..FANOUT).map(|_| tree(depth - 1)).collect(),
+    };
+
+    json!({ "leaves": leaves, "children": children })
+}
+
+fn time(convert: fn(Value) -> Result<Branch>, json: &Value) -> Duration {
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let json = json.clone();
+        let start = Instant::now();
+        convert(json).unwrap();
+        total += start.elapsed();
+    }
+
+    total / ITERATIONS
+}
+
+fn main() {
+    let json = tree(DEPTH);
+    assert_eq!(
+        Branch::from_json(json.clone()),
+        Branch::from_json_fused(json.clone())
+    );
+
+    let two_pass = time(Branch::from_json, &json);
+    let fused = time(Branch::from_json_fused, &json);
+
+    println!("from_json:       {:?}", two_pass);
+    println!("from_json_fused: {:?}", fused);
+    println!(
+        "speedup:         {:.2}x",
+        two_pass.as_secs_f64() / fused.as_secs_f64()
+    );
+}
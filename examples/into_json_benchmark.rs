@@ -0,0 +1,58 @@
+//! Compares `to_json`, which clones every string into the json, with `into_json`, which moves
+//! them out of the consumed value when the struct opts in with `#[jsonable(into_json)]`.
+//!
+//! Run with `cargo run --release --example into_json_benchmark`.
+
+use std::time::{Duration, Instant};
+
+use jsonable::*;
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Jsonable)]
+#[jsonable(into_json)]
+struct Article {
+    pub title: String,
+    pub summary: String,
+    pub body: String,
+    pub footnotes: String,
+}
+
+const FIELD_BYTES: usize = 64 * 1024;
+const ITERATIONS: u32 = 200;
+
+fn article() -> Article {
+    let text = |c: char| std::iter::repeat_n(c, FIELD_BYTES).collect::<String>();
+    Article {
+        title: text('t'),
+        summary: text('s'),
+        body: text('b'),
+        footnotes: text('f'),
+    }
+}
+
+fn time(convert: fn(Article) -> Value, article: &Article) -> Duration {
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let article = article.clone();
+        let start = Instant::now();
+        convert(article);
+        total += start.elapsed();
+    }
+
+    total / ITERATIONS
+}
+
+fn main() {
+    let article = article();
+    assert_eq!(article.to_json(), article.clone().into_json());
+
+    let borrowed = time(|article| article.to_json(), &article);
+    let owned = time(Article::into_json, &article);
+
+    println!("to_json:   {:?}", borrowed);
+    println!("into_json: {:?}", owned);
+    println!(
+        "speedup:   {:.2}x",
+        borrowed.as_secs_f64() / owned.as_secs_f64()
+    );
+}
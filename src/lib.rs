@@ -41,8 +41,81 @@ pub use jsonable_types::*;
 #[test]
 fn ui() {
     let t = trybuild::TestCases::new();
+    t.pass("tests/ui/enum/adjacently_tagged.rs");
+    t.pass("tests/ui/enum/alias.rs");
+    t.pass("tests/ui/enum/deny_unknown_fields.rs");
+    t.pass("tests/ui/enum/field_attributes.rs");
+    t.pass("tests/ui/enum/generic.rs");
     t.pass("tests/ui/enum/happy_path.rs");
+    t.pass("tests/ui/enum/internally_tagged.rs");
+    t.pass("tests/ui/enum/json_schema.rs");
+    t.compile_fail("tests/ui/enum/internally_tagged_tuple.rs");
+    t.pass("tests/ui/enum/no_warnings.rs");
+    t.pass("tests/ui/enum/null_variant.rs");
+    t.pass("tests/ui/enum/numeric.rs");
+    t.pass("tests/ui/enum/object_dispatch.rs");
+    t.pass("tests/ui/enum/raw_identifier.rs");
+    t.pass("tests/ui/enum/recursive.rs");
+    t.pass("tests/ui/enum/rename.rs");
+    t.pass("tests/ui/enum/rename_all.rs");
+    t.pass("tests/ui/enum/tag_field.rs");
+    t.pass("tests/ui/enum/tag_path.rs");
+    t.pass("tests/ui/enum/tuple_field_attributes.rs");
+    t.pass("tests/ui/enum/unit_object.rs");
+    t.pass("tests/ui/enum/unit_payload.rs");
+    t.pass("tests/ui/enum/untagged.rs");
+    t.pass("tests/ui/enum/untagged_field_attributes.rs");
+    t.pass("tests/ui/enum/variant_tag.rs");
+    t.pass("tests/ui/enum/variant_validation.rs");
+    t.compile_fail("tests/ui/hash_map/non_string_key.rs");
+    t.pass("tests/ui/hash_map/string_convertible_key.rs");
+    t.pass("tests/ui/named_structs/alias.rs");
+    t.pass("tests/ui/named_structs/allow_non_finite.rs");
+    t.pass("tests/ui/named_structs/as_hex.rs");
+    t.pass("tests/ui/named_structs/char_field.rs");
+    t.pass("tests/ui/named_structs/coerce_bool.rs");
+    t.pass("tests/ui/named_structs/const_generic.rs");
+    t.pass("tests/ui/named_structs/dedup.rs");
+    t.pass("tests/ui/named_structs/default.rs");
+    t.pass("tests/ui/named_structs/deny_unknown_fields.rs");
+    t.pass("tests/ui/named_structs/drop.rs");
+    t.pass("tests/ui/named_structs/empty_as_none.rs");
+    t.pass("tests/ui/named_structs/flatten.rs");
+    t.pass("tests/ui/named_structs/fused.rs");
+    t.pass("tests/ui/named_structs/generate_patch.rs");
+    t.pass("tests/ui/named_structs/generic.rs");
     t.pass("tests/ui/named_structs/happy_path.rs");
+    t.pass("tests/ui/named_structs/into_json.rs");
+    t.pass("tests/ui/named_structs/items_constraint.rs");
+    t.pass("tests/ui/named_structs/json_schema.rs");
+    t.pass("tests/ui/named_structs/json_string.rs");
+    t.pass("tests/ui/named_structs/lowercase_keys.rs");
+    t.pass("tests/ui/named_structs/max_bytes.rs");
+    t.pass("tests/ui/named_structs/merge_json.rs");
+    t.compile_fail("tests/ui/named_structs/multiple_codecs.rs");
+    t.pass("tests/ui/named_structs/multiple_errors.rs");
+    t.pass("tests/ui/named_structs/null_as_empty.rs");
+    t.pass("tests/ui/named_structs/null_as_zero.rs");
+    t.pass("tests/ui/named_structs/object_as_vec.rs");
+    t.pass("tests/ui/named_structs/raw_identifier.rs");
+    t.pass("tests/ui/named_structs/raw_number.rs");
+    t.pass("tests/ui/named_structs/rename_all.rs");
+    t.pass("tests/ui/named_structs/required.rs");
+    t.pass("tests/ui/named_structs/required_keys.rs");
+    t.pass("tests/ui/named_structs/schema_ref_name.rs");
+    t.pass("tests/ui/named_structs/skip.rs");
+    t.compile_fail("tests/ui/named_structs/skip_without_default.rs");
+    t.pass("tests/ui/named_structs/with.rs");
+    #[cfg(feature = "iso8601")]
+    t.pass("tests/ui/named_structs/duration_iso8601.rs");
+    #[cfg(feature = "serde")]
+    t.pass("tests/ui/named_structs/with_serde.rs");
+    #[cfg(feature = "diff")]
+    t.pass("tests/ui/named_structs/diff.rs");
+    t.pass("tests/ui/tuple_structs/array.rs");
+    t.pass("tests/ui/tuple_structs/as_entry.rs");
+    t.pass("tests/ui/tuple_structs/generic.rs");
     t.pass("tests/ui/tuple_structs/happy_path.rs");
+    t.pass("tests/ui/tuple_structs/transparent.rs");
     t.pass("tests/ui/unit_structs/happy_path.rs");
 }
@@ -0,0 +1,63 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "t", content = "c")]
+enum Message {
+    Ping,
+    Move(i32, i32),
+    Say(String),
+    Resize { width: u32, height: u32 }
+}
+
+fn main() {
+    assert_eq!(Message::Ping.to_json(), json!({ "t": "Ping" }));
+    assert_eq!(Message::from_json(json!({ "t": "Ping" })), Ok(Message::Ping));
+
+    let moved = Message::Move(1, -2);
+    assert_eq!(moved.to_json(), json!({ "t": "Move", "c": [1, -2] }));
+    assert_eq!(Message::from_json(moved.to_json()), Ok(moved));
+
+    let say = Message::Say("hi".into());
+    assert_eq!(say.to_json(), json!({ "t": "Say", "c": "hi" }));
+    assert_eq!(Message::from_json(say.to_json()), Ok(say));
+
+    let resize = Message::Resize { width: 2, height: 3 };
+    assert_eq!(resize.to_json(), json!({ "t": "Resize", "c": { "width": 2, "height": 3 } }));
+    assert_eq!(Message::from_json(json!({ "c": { "width": 2, "height": 3 }, "t": "Resize" })), Ok(resize));
+
+    assert_eq!(
+        Message::validate_json(&json!({ "c": [1, 2] })),
+        Err(JsonableError::MissingEnumTag { ty: "Message", tag: "t" })
+    );
+    assert_eq!(
+        Message::validate_json(&json!("Ping")),
+        Err(JsonableError::IncompatibleJsonType { got: "string", expected: "object" })
+    );
+    assert!(Message::validate_json(&json!({ "t": "Jump" })).is_err());
+    assert!(Message::validate_json(&json!({ "t": "Move", "c": [1] })).is_err());
+    assert!(Message::validate_json(&json!({ "t": "Move", "c": "up" })).is_err());
+
+    // Only unit variants may leave out the content
+    assert_eq!(
+        Message::validate_json(&json!({ "t": "Say" })),
+        Err(JsonableError::MissingKeyForEnumVariant { variant: "Say", key: "c" })
+    );
+    assert_eq!(
+        Message::from_json(json!({ "t": "Resize" })),
+        Err(JsonableError::MissingKeyForEnumVariant { variant: "Resize", key: "c" })
+    );
+    assert_eq!(
+        Message::validate_json(&json!({ "t": "Resize", "c": { "width": 2, "height": "tall" } })),
+        Err(JsonableError::VariantValidation {
+            variant: "Resize",
+            error: Box::from(JsonableError::InvalidMapValue {
+                key: "height".into(),
+                error: Box::from(JsonableError::InnerErrorForType {
+                    ty: "u32",
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+                })
+            })
+        })
+    );
+}
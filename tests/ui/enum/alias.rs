@@ -0,0 +1,48 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Status {
+    #[jsonable(alias = "Done", alias = "Finished")]
+    Complete,
+    Pending,
+    #[jsonable(alias = "Fail")]
+    Failed { reason: String },
+    #[jsonable(alias = "Moved")]
+    Redirect(String),
+    #[jsonable(alias = "Range")]
+    Between(u8, u8)
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "type")]
+enum Event {
+    #[jsonable(alias = "Tap")]
+    Click { x: u32 },
+    #[jsonable(alias = "Noop")]
+    Idle
+}
+
+fn main() {
+    assert_eq!(Status::from_json(json!("Done")), Ok(Status::Complete));
+    assert_eq!(Status::from_json(json!("Finished")), Ok(Status::Complete));
+    assert_eq!(Status::from_json(json!("Complete")), Ok(Status::Complete));
+    assert_eq!(Status::Complete.to_json(), json!("Complete"));
+
+    let failed = Status::from_json(json!({ "Fail": { "reason": "timeout" } })).unwrap();
+    assert_eq!(failed, Status::Failed { reason: "timeout".into() });
+    assert_eq!(failed.to_json(), json!({ "Failed": { "reason": "timeout" } }));
+
+    assert_eq!(Status::from_json(json!({ "Moved": "/new" })), Ok(Status::Redirect("/new".into())));
+    assert!(Status::validate_json(&json!({ "Range": [1, 2] })).is_ok());
+    assert!(Status::validate_json(&json!({ "Moved": 1 })).is_err());
+
+    assert_eq!(
+        Status::validate_json(&json!("Finish")),
+        Err(JsonableError::InvalidEnumStringVariant { enum_type: "Status", got: "Finish".into(), expected: vec!["Complete", "Pending"] })
+    );
+
+    assert_eq!(Event::from_json(json!({ "type": "Tap", "x": 1 })), Ok(Event::Click { x: 1 }));
+    assert_eq!(Event::Click { x: 1 }.to_json(), json!({ "type": "Click", "x": 1 }));
+    assert_eq!(Event::from_json(json!({ "type": "Noop" })), Ok(Event::Idle));
+}
@@ -0,0 +1,38 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(deny_unknown_fields)]
+enum External {
+    Named { count: u16, label: Option<String> },
+    Empty
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "type", deny_unknown_fields)]
+enum Internal {
+    Named { count: u16 },
+    Empty
+}
+
+fn main() {
+    assert!(External::validate_json(&json!({ "Named": { "count": 1 } })).is_ok());
+    assert_eq!(
+        External::validate_json(&json!({ "Named": { "count": 1, "typo": true } })),
+        Err(JsonableError::VariantValidation {
+            variant: "Named",
+            error: Box::from(JsonableError::UnknownField { ty: "External", field: "typo".into() })
+        })
+    );
+
+    // The tag is not an unknown field
+    assert!(Internal::validate_json(&json!({ "type": "Named", "count": 1 })).is_ok());
+    assert!(Internal::validate_json(&json!({ "type": "Empty" })).is_ok());
+    assert_eq!(
+        Internal::from_json(json!({ "type": "Named", "count": 1, "typo": true })),
+        Err(JsonableError::VariantValidation {
+            variant: "Named",
+            error: Box::from(JsonableError::UnknownField { ty: "Internal", field: "typo".into() })
+        })
+    );
+}
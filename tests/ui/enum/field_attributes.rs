@@ -0,0 +1,42 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Message {
+    Blob {
+        #[jsonable(as_hex)]
+        data: Vec<u8>,
+        #[jsonable(items(max = 1))]
+        tags: Vec<String>
+    },
+    Empty
+}
+
+fn main() {
+    let blob = Message::Blob { data: vec![0xbe, 0xef], tags: vec!["a".into()] };
+    let json = blob.to_json();
+    assert_eq!(json, json!({ "Blob": { "data": "beef", "tags": ["a"] } }));
+    assert_eq!(Message::from_json(json), Ok(blob));
+
+    // Field attributes of struct variants apply as they do on struct fields
+    assert!(Message::validate_json(&json!({ "Blob": { "data": [190, 239], "tags": [] } })).is_err());
+    assert_eq!(
+        Message::validate_json(&json!({ "Blob": { "data": "beef", "tags": ["a", "b"] } })),
+        Err(JsonableError::VariantValidation {
+            variant: "Blob",
+            error: Box::from(JsonableError::InvalidMapValue {
+                key: "tags".into(),
+                error: Box::from(JsonableError::InnerErrorForType {
+                    ty: std::any::type_name::<Vec<String>>(),
+                    error: Box::from(JsonableError::CollectionTooLong { got: 2, max: 1 })
+                })
+            })
+        })
+    );
+
+    let schema = Message::json_schema();
+    assert_eq!(
+        schema.pointer("/oneOf/1/properties/Blob/properties/data"),
+        Some(&json!({ "type": "string", "pattern": "^([0-9a-fA-F]{2})*$" }))
+    );
+}
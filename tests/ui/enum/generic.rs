@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Tagged<'a, T, const N: usize> {
+    Empty,
+    Named(Cow<'a, str>),
+    Values { values: [T; N] },
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "type")]
+enum Internal<T> {
+    Value { value: T },
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(untagged)]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+fn main() {
+    let tagged = Tagged::<u8, 2>::from_json(json!({ "Values": { "values": [1, 2] } })).unwrap();
+    assert_eq!(tagged, Tagged::Values { values: [1, 2] });
+    assert_eq!(tagged.json_variant_tag(), "Values");
+    assert_eq!(Tagged::<u8, 2>::Named("one".into()).to_json(), json!({ "Named": "one" }));
+    assert!(Tagged::<u8, 3>::validate_json(&json!({ "Values": { "values": [1, 2] } })).is_err());
+
+    let internal = Internal::<String>::from_json(json!({ "type": "Value", "value": "one" })).unwrap();
+    assert_eq!(internal, Internal::Value { value: "one".into() });
+
+    assert_eq!(Either::<u8, String>::from_json(json!("two")), Ok(Either::Right("two".into())));
+    assert_eq!(Either::<u8, String>::Left(1).to_json(), json!(1));
+}
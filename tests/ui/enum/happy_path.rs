@@ -1,12 +1,13 @@
 use jsonable::*;
+use serde_json::json;
 
-#[derive(Jsonable)]
+#[derive(Debug, PartialEq, Jsonable)]
 enum Simple {
     Value,
     Value2
 }
 
-#[derive(Jsonable)]
+#[derive(Debug, PartialEq, Jsonable)]
 enum ComplexUnnamed {
     Single(u32),
     OptionalSingle(Option<u32>),
@@ -16,4 +17,23 @@ enum ComplexUnnamed {
     NamedMultiple{ gregistan: isize, count: u16, marker: Option<Simple> }
 }
 
-fn main()  {}
\ No newline at end of file
+fn main() {
+    let multiple = ComplexUnnamed::Multiple(1, 2);
+    assert_eq!(multiple.to_json(), json!({ "Multiple": [1, 2] }));
+    assert_eq!(ComplexUnnamed::from_json(multiple.to_json()), Ok(multiple));
+
+    // Optional fields of a struct variant may be left out
+    let partial = json!({ "NamedMultiple": { "gregistan": -1, "count": 2 } });
+    assert!(ComplexUnnamed::validate_json(&partial).is_ok());
+    assert_eq!(
+        ComplexUnnamed::from_json(partial),
+        Ok(ComplexUnnamed::NamedMultiple { gregistan: -1, count: 2, marker: None })
+    );
+    assert_eq!(
+        ComplexUnnamed::validate_json(&json!({ "NamedMultiple": { "gregistan": -1 } })),
+        Err(JsonableError::VariantValidation {
+            variant: "NamedMultiple",
+            error: Box::from(JsonableError::MissingKeyForEnumVariant { variant: "NamedMultiple", key: "count" })
+        })
+    );
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "type")]
+enum Shape {
+    Circle { radius: u32 },
+    Square { side: u32, label: Option<String> },
+    Empty
+}
+
+fn main() {
+    let circle = Shape::from_json(json!({ "type": "Circle", "radius": 3 })).unwrap();
+    assert_eq!(circle, Shape::Circle { radius: 3 });
+    assert_eq!(circle.to_json(), json!({ "type": "Circle", "radius": 3 }));
+
+    let square = Shape::Square { side: 2, label: None };
+    assert_eq!(square.to_json(), json!({ "type": "Square", "side": 2, "label": null }));
+    assert_eq!(Shape::from_json(json!({ "type": "Square", "side": 2 })), Ok(square));
+
+    // Like serde, the tag may sit anywhere in the object and unknown keys are ignored
+    assert_eq!(
+        Shape::from_json(json!({ "radius": 3, "extra": true, "type": "Circle" })),
+        Ok(Shape::Circle { radius: 3 })
+    );
+
+    assert_eq!(Shape::Empty.to_json(), json!({ "type": "Empty" }));
+    assert_eq!(Shape::from_json(json!({ "type": "Empty" })), Ok(Shape::Empty));
+
+    // Unknown tags are reported before any of the fields are looked at
+    assert_eq!(
+        Shape::validate_json(&json!({ "type": "Triangle", "radius": "not a number" })),
+        Err(JsonableError::InvalidEnumStringVariant { enum_type: "Shape", got: "Triangle".into(), expected: vec!["Circle", "Square", "Empty"] })
+    );
+    assert_eq!(
+        Shape::validate_json(&json!({ "radius": 3 })),
+        Err(JsonableError::MissingEnumTag { ty: "Shape", tag: "type" })
+    );
+}
@@ -0,0 +1,10 @@
+use jsonable::*;
+
+#[derive(Jsonable)]
+#[jsonable(tag = "type")]
+enum Shape {
+    Circle { radius: u32 },
+    Point(i32, i32)
+}
+
+fn main() {}
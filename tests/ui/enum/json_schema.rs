@@ -0,0 +1,52 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Jsonable)]
+enum Shape {
+    Empty,
+    Circle(f32),
+    Point(i8, i8),
+    Rect { width: u8, height: Option<u8> },
+}
+
+#[derive(Jsonable)]
+#[jsonable(tag = "kind")]
+enum Event {
+    Start,
+    Stop { code: u8 },
+}
+
+#[derive(Jsonable)]
+#[jsonable(untagged)]
+enum Id {
+    Number(u8),
+    Missing,
+}
+
+#[derive(Jsonable)]
+#[jsonable(numeric)]
+enum Level {
+    Low = 1,
+    High = 5,
+}
+
+fn main() {
+    let schema = Shape::json_schema();
+    let variants = schema["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 5);
+    assert_eq!(variants[0], json!({ "type": "string", "enum": ["Empty"] }));
+    assert_eq!(variants[3]["properties"]["Point"]["prefixItems"], json!([i8::json_schema(), i8::json_schema()]));
+    assert_eq!(variants[4]["properties"]["Rect"]["required"], json!(["width"]));
+
+    let schema = Event::json_schema();
+    let variants = schema["oneOf"].as_array().unwrap();
+    assert_eq!(variants[0]["properties"]["kind"], json!({ "const": "Start" }));
+    assert_eq!(variants[1]["required"], json!(["kind", "code"]));
+
+    assert_eq!(
+        Id::json_schema(),
+        json!({ "anyOf": [u8::json_schema(), { "type": "null" }] })
+    );
+
+    assert_eq!(Level::json_schema(), json!({ "type": "integer", "enum": [1, 5] }));
+}
@@ -0,0 +1,54 @@
+#![deny(warnings)]
+
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Unit {
+    Value,
+    Value2
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Payload {
+    Single(u32),
+    Multiple(u32, u16),
+    Named { count: u16 }
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Mixed {
+    Empty,
+    Single(u32)
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "type")]
+enum Tagged {
+    Empty,
+    Named { count: u16 }
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(untagged)]
+enum Untagged {
+    Number(u32),
+    Text(String)
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(numeric)]
+enum Numeric {
+    Zero,
+    One
+}
+
+fn main() {
+    assert_eq!(Unit::from_json(json!("Value2")), Ok(Unit::Value2));
+    assert_eq!(Payload::from_json(json!({ "Named": { "count": 3 } })), Ok(Payload::Named { count: 3 }));
+    assert_eq!(Mixed::from_json(json!("Empty")), Ok(Mixed::Empty));
+    assert!(Mixed::validate_json(&json!("Other")).is_err());
+    assert_eq!(Tagged::from_json(json!({ "type": "Named", "count": 3 })), Ok(Tagged::Named { count: 3 }));
+    assert_eq!(Untagged::from_json(json!("text")), Ok(Untagged::Text("text".into())));
+    assert_eq!(Numeric::from_json(json!(1)), Ok(Numeric::One));
+}
@@ -0,0 +1,38 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(null_variant = "Unspecified")]
+enum Preference {
+    Unspecified,
+    Light,
+    Custom { theme: String }
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "kind", null_variant = "Nothing")]
+enum Event {
+    Nothing,
+    Click { x: u32 }
+}
+
+fn main() {
+    assert_eq!(Preference::Unspecified.to_json(), json!(null));
+    assert_eq!(Preference::from_json(json!(null)), Ok(Preference::Unspecified));
+
+    assert_eq!(Preference::Light.to_json(), json!("Light"));
+    assert_eq!(Preference::from_json(json!("Light")), Ok(Preference::Light));
+    let custom = Preference::Custom { theme: "dark".into() };
+    assert_eq!(Preference::from_json(custom.to_json()), Ok(custom));
+
+    // The null variant is no longer written or read by name
+    assert!(Preference::validate_json(&json!("Unspecified")).is_err());
+    assert_eq!(
+        Preference::validate_json(&json!(1)),
+        Err(JsonableError::IncompatibleJsonType { got: "number", expected: "object, string, or null" })
+    );
+
+    assert_eq!(Event::Nothing.to_json(), json!(null));
+    assert_eq!(Event::from_json(json!(null)), Ok(Event::Nothing));
+    assert_eq!(Event::from_json(json!({ "kind": "Click", "x": 4 })), Ok(Event::Click { x: 4 }));
+}
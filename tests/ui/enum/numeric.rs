@@ -0,0 +1,31 @@
+use jsonable::*;
+use serde_json::{json, Number};
+
+#[repr(u16)]
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(numeric)]
+enum TestEnum {
+    Value,
+    Value2 = 1,
+    Value3 = 10,
+    Value4
+}
+
+fn main() {
+    assert_eq!(TestEnum::Value2.to_json(), json!(1));
+    assert_eq!(TestEnum::from_json(json!(1)), Ok(TestEnum::Value2));
+    assert_eq!(TestEnum::from_json(json!(0)), Ok(TestEnum::Value));
+    assert_eq!(TestEnum::Value4.to_json(), json!(11));
+    assert_eq!(TestEnum::from_json(TestEnum::Value3.to_json()), Ok(TestEnum::Value3));
+    assert_eq!(TestEnum::Value3.json_variant_tag(), "Value3");
+
+    assert_eq!(
+        TestEnum::validate_json(&json!(2)),
+        Err(JsonableError::InvalidEnumDiscriminant { enum_type: "TestEnum", got: Number::from(2), expected: vec![0, 1, 10, 11] })
+    );
+    assert!(TestEnum::validate_json(&json!(1.5)).is_err());
+    assert_eq!(
+        TestEnum::validate_json(&json!("Value2")),
+        Err(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+    );
+}
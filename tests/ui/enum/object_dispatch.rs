@@ -0,0 +1,34 @@
+use jsonable::*;
+use serde_json::{json, Map, Value};
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Wrapper {
+    Single(u8),
+    Pair(u8, u8),
+    #[jsonable(alias = "Obj")]
+    Named { value: u8 },
+    Empty
+}
+
+fn main() {
+    assert_eq!(Wrapper::from_json(json!({ "Single": 5 })), Ok(Wrapper::Single(5)));
+    assert_eq!(Wrapper::from_json(json!({ "Pair": [1, 2] })), Ok(Wrapper::Pair(1, 2)));
+    assert_eq!(Wrapper::from_json(json!({ "Obj": { "value": 3 } })), Ok(Wrapper::Named { value: 3 }));
+
+    // Keys sorting before and after the variant do not change which variant is read
+    for extra in ["AAA", "zzz"] {
+        let mut map = Map::new();
+        map.insert(extra.into(), json!(true));
+        map.insert("Single".into(), json!(5));
+        assert_eq!(Wrapper::from_json_unchecked(Value::Object(map)), Wrapper::Single(5));
+    }
+
+    assert_eq!(
+        Wrapper::validate_json(&json!({ "Other": 5 })),
+        Err(JsonableError::IncorrectKeyForEnum {
+            ty: "Wrapper",
+            key: "Other".into(),
+            expected: vec!["Single", "Pair", "Named", "Empty"]
+        })
+    );
+}
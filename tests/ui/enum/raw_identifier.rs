@@ -0,0 +1,34 @@
+use jsonable::*;
+use serde_json::json;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Jsonable)]
+enum Keyword {
+    r#match,
+    r#type { name: String },
+    r#loop(u8)
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "kind", null_variant = "none")]
+enum Tagged {
+    r#none,
+    r#struct { size: u8 }
+}
+
+fn main() {
+    assert_eq!(Keyword::r#match.to_json(), json!("match"));
+    assert_eq!(Keyword::from_json(json!("match")), Ok(Keyword::r#match));
+
+    let ty = Keyword::r#type { name: "u8".into() };
+    assert_eq!(ty.to_json(), json!({ "type": { "name": "u8" } }));
+    assert_eq!(Keyword::from_json(ty.to_json()), Ok(ty));
+
+    assert_eq!(Keyword::r#loop(3).to_json(), json!({ "loop": 3 }));
+    assert_eq!(Keyword::from_json(json!({ "loop": 3 })), Ok(Keyword::r#loop(3)));
+
+    assert_eq!(Tagged::r#struct { size: 1 }.to_json(), json!({ "kind": "struct", "size": 1 }));
+    assert_eq!(Tagged::from_json(json!({ "kind": "struct", "size": 1 })), Ok(Tagged::r#struct { size: 1 }));
+    assert_eq!(Tagged::from_json(json!(null)), Ok(Tagged::r#none));
+}
@@ -0,0 +1,55 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Expr {
+    Num(u32),
+    Neg(Box<Expr>),
+    Add { left: Box<Expr>, right: Box<Expr> }
+}
+
+fn main() {
+    let expr = Expr::Add {
+        left: Box::new(Expr::Num(1)),
+        right: Box::new(Expr::Neg(Box::new(Expr::Num(2))))
+    };
+    let json = expr.to_json();
+    assert_eq!(json, json!({ "Add": { "left": { "Num": 1 }, "right": { "Neg": { "Num": 2 } } } }));
+    assert_eq!(Expr::from_json(json), Ok(expr));
+
+    assert!(Expr::validate_json(&json!({ "Neg": { "Num": "two" } })).is_err());
+
+    // Nested `Expr`s refer back to the root schema, kept under `$defs`
+    let expr_ref = json!({ "$ref": "#/$defs/Expr" });
+    let mut schema = json!({
+        "oneOf": [
+            {
+                "type": "object",
+                "properties": { "Num": u32::json_schema() },
+                "required": ["Num"],
+                "additionalProperties": false
+            },
+            {
+                "type": "object",
+                "properties": { "Neg": expr_ref },
+                "required": ["Neg"],
+                "additionalProperties": false
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "Add": {
+                        "type": "object",
+                        "properties": { "left": expr_ref, "right": expr_ref },
+                        "required": ["left", "right"]
+                    }
+                },
+                "required": ["Add"],
+                "additionalProperties": false
+            }
+        ]
+    });
+    schema["$defs"] = json!({ "Expr": schema.clone() });
+    assert_eq!(Expr::json_schema(), schema);
+    assert_eq!(Vec::<Expr>::json_schema()["$defs"]["Expr"], schema["$defs"]["Expr"]);
+}
@@ -0,0 +1,34 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Status {
+    #[jsonable(rename = "in_progress")]
+    InProgress,
+    Done
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(rename_all = "SCREAMING_SNAKE_CASE", null_variant = "Unknown")]
+enum Job {
+    #[jsonable(rename = "job:queued")]
+    Queued,
+    RunningNow { pid: u32 },
+    Unknown
+}
+
+fn main() {
+    assert_eq!(Status::InProgress.to_json(), json!("in_progress"));
+    assert_eq!(Status::from_json(json!("in_progress")), Ok(Status::InProgress));
+    assert_eq!(Status::InProgress.json_variant_tag(), "in_progress");
+    assert!(Status::validate_json(&json!("InProgress")).is_err());
+    assert_eq!(Status::Done.to_json(), json!("Done"));
+
+    // An explicit rename wins over the enum's rule
+    assert_eq!(Job::Queued.to_json(), json!("job:queued"));
+    assert_eq!(Job::from_json(json!("job:queued")), Ok(Job::Queued));
+    assert_eq!(Job::RunningNow { pid: 1 }.to_json(), json!({ "RUNNING_NOW": { "pid": 1 } }));
+    assert_eq!(Job::from_json(json!({ "RUNNING_NOW": { "pid": 1 } })), Ok(Job::RunningNow { pid: 1 }));
+    assert_eq!(Job::from_json(json!(null)), Ok(Job::Unknown));
+    assert_eq!(Job::Unknown.json_variant_tag(), "UNKNOWN");
+}
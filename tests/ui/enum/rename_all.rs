@@ -0,0 +1,32 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(rename_all = "snake_case")]
+enum Event {
+    UserLogin { user_name: String },
+    #[jsonable(rename_all = "camelCase")]
+    UserLogout { user_name: String, #[jsonable(rename = "why")] logout_reason: String }
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "kind", rename_all = "kebab-case")]
+enum Message {
+    #[jsonable(rename_all = "kebab-case")]
+    PlainText { body_text: String }
+}
+
+fn main() {
+    // The enum's rule renames variants, a variant's own rule renames its fields
+    let login = Event::UserLogin { user_name: "ada".into() };
+    assert_eq!(login.to_json(), json!({ "user_login": { "user_name": "ada" } }));
+    assert_eq!(Event::from_json(login.to_json()), Ok(login));
+
+    let logout = Event::UserLogout { user_name: "ada".into(), logout_reason: "idle".into() };
+    assert_eq!(logout.to_json(), json!({ "user_logout": { "userName": "ada", "why": "idle" } }));
+    assert_eq!(Event::from_json(logout.to_json()), Ok(logout));
+
+    let text = Message::PlainText { body_text: "hi".into() };
+    assert_eq!(text.to_json(), json!({ "kind": "plain-text", "body-text": "hi" }));
+    assert_eq!(Message::from_json(text.to_json()), Ok(text));
+}
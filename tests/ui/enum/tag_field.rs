@@ -0,0 +1,26 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "kind")]
+enum Event {
+    Click { kind: String, x: u32 },
+    #[jsonable(alias = "KeyDown")]
+    Key { kind: String, code: u32 },
+    Idle
+}
+
+fn main() {
+    let click = Event::from_json(json!({ "kind": "Click", "x": 4 })).unwrap();
+    assert_eq!(click, Event::Click { kind: "Click".into(), x: 4 });
+    assert_eq!(click.to_json(), json!({ "kind": "Click", "x": 4 }));
+
+    // The alias that selected the variant is kept in the field
+    let key = Event::from_json(json!({ "kind": "KeyDown", "code": 13 })).unwrap();
+    assert_eq!(key, Event::Key { kind: "KeyDown".into(), code: 13 });
+
+    // The variant name is written to the tag, whatever the field holds
+    assert_eq!(key.to_json(), json!({ "kind": "Key", "code": 13 }));
+
+    assert_eq!(Event::from_json(json!({ "kind": "Idle" })), Ok(Event::Idle));
+}
@@ -0,0 +1,29 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag_path = "/event/type")]
+enum Shape {
+    Circle { radius: u32 },
+    Square(u32),
+    Empty
+}
+
+fn main() {
+    let circle = Shape::Circle { radius: 3 };
+    let json = circle.to_json();
+    assert_eq!(json, json!({ "event": { "type": { "Circle": { "radius": 3 } } } }));
+    assert_eq!(Shape::from_json(json), Ok(circle));
+
+    assert_eq!(Shape::Empty.to_json(), json!({ "event": { "type": "Empty" } }));
+    assert_eq!(Shape::from_json(json!({ "event": { "type": { "Square": 2 } } })), Ok(Shape::Square(2)));
+
+    // Keys next to the path are ignored
+    assert_eq!(Shape::from_json(json!({ "event": { "id": 7, "type": "Empty" }, "version": 1 })), Ok(Shape::Empty));
+
+    assert_eq!(
+        Shape::validate_json(&json!({ "event": { "kind": "Empty" } })),
+        Err(JsonableError::MissingEnumTag { ty: "Shape", tag: "/event/type" })
+    );
+    assert!(Shape::validate_json(&json!({ "event": { "type": { "Circle": { "radius": "big" } } } })).is_err());
+}
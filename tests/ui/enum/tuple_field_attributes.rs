@@ -0,0 +1,47 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Frame {
+    Single(#[jsonable(as_hex)] Vec<u8>),
+    Pair(#[jsonable(as_hex)] Vec<u8>, #[jsonable(items(max = 1))] Vec<u32>)
+}
+
+fn main() {
+    let single = Frame::Single(vec![0xca, 0xfe]);
+    let json = single.to_json();
+    assert_eq!(json, json!({ "Single": "cafe" }));
+    assert_eq!(Frame::from_json(json), Ok(single));
+
+    let pair = Frame::Pair(vec![0x01], vec![7]);
+    let json = pair.to_json();
+    assert_eq!(json, json!({ "Pair": ["01", [7]] }));
+    assert_eq!(Frame::from_json(json), Ok(pair));
+
+    // Field attributes of tuple variants apply as they do on tuple struct fields
+    assert_eq!(
+        Frame::validate_json(&json!({ "Single": "abc" })),
+        Err(JsonableError::VariantValidation {
+            variant: "Single",
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<Vec<u8>>(),
+                error: Box::from(JsonableError::InvalidFormat { expected: "hex string", got: "abc".into() })
+            })
+        })
+    );
+    assert_eq!(
+        Frame::validate_json(&json!({ "Pair": ["01", [1, 2]] })),
+        Err(JsonableError::VariantValidation {
+            variant: "Pair",
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<Vec<u32>>(),
+                error: Box::from(JsonableError::CollectionTooLong { got: 2, max: 1 })
+            })
+        })
+    );
+
+    let schema = Frame::json_schema();
+    let hex = json!({ "type": "string", "pattern": "^([0-9a-fA-F]{2})*$" });
+    assert_eq!(schema.pointer("/oneOf/0/properties/Single"), Some(&hex));
+    assert_eq!(schema.pointer("/oneOf/1/properties/Pair/prefixItems/0"), Some(&hex));
+}
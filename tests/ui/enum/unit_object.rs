@@ -0,0 +1,30 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Light {
+    #[jsonable(alias = "Stop")]
+    Red,
+    Green(u8)
+}
+
+fn main() {
+    assert_eq!(Light::Red.to_json(), json!("Red"));
+
+    assert_eq!(Light::from_json(json!("Red")), Ok(Light::Red));
+    assert_eq!(Light::from_json(json!({ "Red": null })), Ok(Light::Red));
+    assert_eq!(Light::from_json(json!({ "Red": {} })), Ok(Light::Red));
+    assert_eq!(Light::from_json(json!({ "Stop": {} })), Ok(Light::Red));
+
+    assert_eq!(
+        Light::validate_json(&json!({ "Red": { "brightness": 3 } })),
+        Err(JsonableError::UnknownField { ty: "Light", field: "brightness".into() })
+    );
+    assert_eq!(
+        Light::validate_json(&json!({ "Red": 3 })),
+        Err(JsonableError::VariantValidation {
+            variant: "Red",
+            error: Box::from(JsonableError::IncompatibleJsonType { got: "number", expected: "object or null" })
+        })
+    );
+}
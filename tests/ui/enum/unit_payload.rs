@@ -0,0 +1,16 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Signal {
+    Ping(()),
+    Value(u8)
+}
+
+fn main() {
+    let json = Signal::Ping(()).to_json();
+    assert_eq!(json, json!({ "Ping": null }));
+    assert_eq!(Signal::from_json(json), Ok(Signal::Ping(())));
+
+    assert!(Signal::validate_json(&json!({ "Ping": {} })).is_err());
+}
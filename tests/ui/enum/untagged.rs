@@ -0,0 +1,56 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(untagged)]
+enum Id {
+    Number(u32),
+    Name(String)
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(untagged)]
+enum Shape {
+    Nothing,
+    Point(i32, i32),
+    Circle { radius: u32 },
+    Rect { width: u32, height: u32 },
+    Empty {}
+}
+
+/// `Option<u32>` accepts every number, so `Count` is never read
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(untagged)]
+enum Shadowed {
+    Maybe(Option<u32>),
+    Count(u32)
+}
+
+fn main() {
+    assert_eq!(Id::from_json(json!(5)), Ok(Id::Number(5)));
+    assert_eq!(Id::from_json(json!("x")), Ok(Id::Name("x".into())));
+    assert_eq!(Id::Number(5).to_json(), json!(5));
+    assert_eq!(Id::Name("x".into()).to_json(), json!("x"));
+    assert_eq!(
+        Id::validate_json(&json!(true)),
+        Err(JsonableError::NoUntaggedVariantMatched { ty: "Id" })
+    );
+
+    for shape in [
+        Shape::Nothing,
+        Shape::Point(1, -1),
+        Shape::Circle { radius: 2 },
+        Shape::Rect { width: 3, height: 4 },
+    ] {
+        assert_eq!(Shape::from_json(shape.to_json()), Ok(shape));
+    }
+    assert_eq!(Shape::Point(1, -1).to_json(), json!([1, -1]));
+    assert_eq!(Shape::Rect { width: 3, height: 4 }.to_json(), json!({ "width": 3, "height": 4 }));
+
+    // `Circle` is tried first and accepts the object, since unknown keys are ignored
+    assert_eq!(Shape::from_json(json!({ "radius": 1, "width": 3, "height": 4 })), Ok(Shape::Circle { radius: 1 }));
+    assert_eq!(Shape::from_json(json!({ "other": 1 })), Ok(Shape::Empty {}));
+    assert!(Shape::validate_json(&json!([1, 2, 3])).is_err());
+
+    assert_eq!(Shadowed::from_json(json!(3)), Ok(Shadowed::Maybe(Some(3))));
+}
@@ -0,0 +1,36 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(untagged)]
+enum Payload {
+    Pair(#[jsonable(items(max = 1))] Vec<u32>, #[jsonable(as_hex)] Vec<u8>),
+    Hex(#[jsonable(as_hex)] Vec<u8>),
+    Text(String)
+}
+
+fn main() {
+    let hex = Payload::Hex(vec![0xbe, 0xef]);
+    let json = hex.to_json();
+    assert_eq!(json, json!("beef"));
+    assert_eq!(Payload::from_json(json), Ok(hex));
+
+    let pair = Payload::Pair(vec![1], vec![0x0a]);
+    let json = pair.to_json();
+    assert_eq!(json, json!([[1], "0a"]));
+    assert_eq!(Payload::from_json(json), Ok(pair));
+
+    // A string that is not hex falls through to the next variant
+    assert_eq!(Payload::from_json(json!("hello")), Ok(Payload::Text("hello".into())));
+
+    // The items constraint rules out the pair, and no other variant takes an array
+    assert_eq!(
+        Payload::validate_json(&json!([[1, 2], "0a"])),
+        Err(JsonableError::NoUntaggedVariantMatched { ty: "Payload" })
+    );
+
+    let schema = Payload::json_schema();
+    let hex = json!({ "type": "string", "pattern": "^([0-9a-fA-F]{2})*$" });
+    assert_eq!(schema.pointer("/anyOf/1"), Some(&hex));
+    assert_eq!(schema.pointer("/anyOf/0/prefixItems/1"), Some(&hex));
+}
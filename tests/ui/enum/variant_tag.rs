@@ -0,0 +1,32 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Message {
+    Ping,
+    #[jsonable(alias = "Say")]
+    Text(String),
+    Move { x: i32, y: i32 },
+    r#Quit
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "kind")]
+enum Shape {
+    Circle { radius: u32 },
+    Empty
+}
+
+fn main() {
+    assert_eq!(Message::Ping.json_variant_tag(), "Ping");
+    assert_eq!(Message::Text("hi".into()).json_variant_tag(), "Text");
+    assert_eq!(Message::Move { x: 1, y: 2 }.json_variant_tag(), "Move");
+    assert_eq!(Message::Quit.json_variant_tag(), "Quit");
+
+    let message = Message::from_json(json!({ "Say": "hi" })).unwrap();
+    assert_eq!(message.json_variant_tag(), "Text");
+
+    assert_eq!(Shape::Circle { radius: 2 }.json_variant_tag(), "Circle");
+    assert_eq!(Shape::Empty.json_variant_tag(), "Empty");
+    assert_eq!(Shape::Empty.to_json(), json!({ "kind": Shape::Empty.json_variant_tag() }));
+}
@@ -0,0 +1,68 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(tag = "type")]
+enum Shape {
+    Circle { radius: u32 },
+    #[jsonable(alias = "Rect")]
+    Rectangle { width: u32, height: u32 },
+    Empty
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Wrapper {
+    Single(u32)
+}
+
+fn main() {
+    assert_eq!(
+        Shape::validate_json(&json!({ "type": "Circle", "radius": "wide" })),
+        Err(JsonableError::VariantValidation {
+            variant: "Circle",
+            error: Box::from(JsonableError::InvalidMapValue {
+                key: "radius".into(),
+                error: Box::from(JsonableError::InnerErrorForType {
+                    ty: "u32",
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+                })
+            })
+        })
+    );
+
+    // Missing fields are reported by key, all of them at once
+    assert_eq!(
+        Shape::validate_json(&json!({ "type": "Rectangle" })),
+        Err(JsonableError::VariantValidation {
+            variant: "Rectangle",
+            error: Box::from(JsonableError::InnerErrorsForType {
+                ty: "Shape",
+                errors: vec![
+                    JsonableError::MissingKeyForEnumVariant { variant: "Rectangle", key: "width" },
+                    JsonableError::MissingKeyForEnumVariant { variant: "Rectangle", key: "height" },
+                ]
+            })
+        })
+    );
+
+    // The variant's own name is reported even when it was selected by an alias
+    match Shape::from_json(json!({ "type": "Rect", "width": 1 })) {
+        Err(JsonableError::VariantValidation { variant, .. }) => assert_eq!(variant, "Rectangle"),
+        other => panic!("Unexpected result {:?}", other)
+    }
+
+    assert!(Shape::validate_json(&json!({ "type": "Circle", "radius": 2 })).is_ok());
+
+    let error = Wrapper::validate_json(&json!({ "Single": "notanumber" })).unwrap_err();
+    assert_eq!(
+        error,
+        JsonableError::VariantValidation {
+            variant: "Single",
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: "u32",
+                error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            })
+        }
+    );
+    assert_eq!(error.to_string(), "variant `Single` -> u32 -> expected a JSON number but got string");
+}
@@ -0,0 +1,10 @@
+use jsonable::*;
+
+use std::collections::HashMap;
+
+#[derive(Jsonable)]
+struct Scores {
+    pub by_id: HashMap<u32, u8>
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+use jsonable::*;
+use serde_json::json;
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Name(String);
+
+impl From<String> for Name {
+    fn from(name: String) -> Self {
+        Name(name)
+    }
+}
+
+impl From<Name> for String {
+    fn from(name: Name) -> Self {
+        name.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct UserId(u32);
+
+impl JsonMapKey for UserId {
+    fn from_key(key: String) -> Self {
+        UserId(key.parse().unwrap())
+    }
+
+    fn to_key(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[derive(Jsonable)]
+struct Scores {
+    pub by_name: HashMap<Name, u8>,
+    pub by_id: HashMap<UserId, u8>,
+}
+
+fn main() {
+    let json = json!({ "by_name": { "ada": 3 }, "by_id": { "7": 5 } });
+    let scores = Scores::from_json(json.clone()).unwrap();
+
+    assert_eq!(scores.by_name[&Name("ada".into())], 3);
+    assert_eq!(scores.by_id[&UserId(7)], 5);
+    assert_eq!(scores.to_json(), json);
+}
@@ -0,0 +1,32 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(deny_unknown_fields, generate_patch)]
+struct Theme {
+    #[jsonable(alias = "colour", alias = "col")]
+    pub color: String,
+    #[jsonable(default, alias = "sz")]
+    pub size: u8
+}
+
+fn main() {
+    let red = || Theme { color: "red".into(), size: 0 };
+
+    assert_eq!(Theme::from_json(json!({ "color": "red" })), Ok(red()));
+    assert_eq!(Theme::from_json(json!({ "colour": "red" })), Ok(red()));
+    assert_eq!(Theme::from_json(json!({ "col": "red" })), Ok(red()));
+    assert_eq!(Theme::from_json_fused(json!({ "colour": "red" })), Ok(red()));
+    assert_eq!(Theme::from_json(json!({ "colour": "red" })).unwrap().to_json(), json!({ "color": "red", "size": 0 }));
+
+    // The canonical key wins over any alias, in validation as well as conversion
+    let both = json!({ "colour": 1, "color": "red", "sz": 4 });
+    let expected = Theme { color: "red".into(), size: 4 };
+    assert_eq!(Theme::from_json(both.clone()), Ok(expected));
+    assert_eq!(Theme::from_json_fused(both), Ok(Theme { color: "red".into(), size: 4 }));
+
+    assert!(Theme::validate_json(&json!({ "colour": 1 })).is_err());
+
+    let patch = ThemePatch::from_json(json!({ "colour": "blue" })).unwrap();
+    assert_eq!(patch.color, Some("blue".into()));
+}
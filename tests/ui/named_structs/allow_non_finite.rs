@@ -0,0 +1,51 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, Jsonable)]
+struct Reading {
+    #[jsonable(allow_non_finite)]
+    pub value: f64,
+    #[jsonable(allow_non_finite)]
+    pub low: f32
+}
+
+#[derive(Debug, Jsonable)]
+struct PlainReading {
+    pub value: f64,
+    pub history: Vec<f64>
+}
+
+fn main() {
+    let reading = Reading::from_json(json!({ "value": "NaN", "low": 1.5 })).unwrap();
+    assert!(reading.value.is_nan());
+    assert_eq!(reading.low, 1.5);
+    assert_eq!(reading.to_json()["value"], json!("NaN"));
+
+    let reading = Reading::from_json(json!({ "value": "Infinity", "low": "-Infinity" })).unwrap();
+    assert_eq!(reading.value, f64::INFINITY);
+    assert_eq!(reading.low, f32::NEG_INFINITY);
+    assert_eq!(reading.to_json(), json!({ "value": "Infinity", "low": "-Infinity" }));
+
+    let reading = Reading::from_json(json!({ "value": "-Infinity", "low": 0 })).unwrap();
+    assert_eq!(reading.value, f64::NEG_INFINITY);
+
+    assert_eq!(
+        Reading::validate_json(&json!({ "value": "nan", "low": 0 })),
+        Err(JsonableError::InvalidMapValue {
+            key: "value".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: "f64",
+                error: Box::from(JsonableError::InvalidFormat { expected: "\"NaN\", \"Infinity\", or \"-Infinity\"", got: "nan".into() })
+            })
+        })
+    );
+
+    // Plain floats read the strings they write as well, also inside collections
+    let reading = PlainReading { value: f64::INFINITY, history: vec![1.5, f64::NEG_INFINITY] };
+    let json = reading.to_json();
+    assert_eq!(json, json!({ "value": "Infinity", "history": [1.5, "-Infinity"] }));
+    let read = PlainReading::from_json(json).unwrap();
+    assert_eq!(read.value, f64::INFINITY);
+    assert_eq!(read.history, vec![1.5, f64::NEG_INFINITY]);
+    assert!(PlainReading::from_json(json!({ "value": "NaN", "history": [] })).unwrap().value.is_nan());
+}
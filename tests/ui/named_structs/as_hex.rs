@@ -0,0 +1,42 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Packet {
+    #[jsonable(as_hex)]
+    pub payload: Vec<u8>,
+    #[jsonable(as_hex)]
+    pub checksum: [u8; 2]
+}
+
+fn main() {
+    let packet = Packet { payload: vec![0xde, 0xad, 0xbe, 0xef, 0x00], checksum: [0x0a, 0xff] };
+    let json = packet.to_json();
+    assert_eq!(json, json!({ "payload": "deadbeef00", "checksum": "0aff" }));
+    assert_eq!(Packet::from_json(json), Ok(packet));
+
+    let upper = Packet::from_json(json!({ "payload": "CAFE", "checksum": "0A0B" })).unwrap();
+    assert_eq!(upper, Packet { payload: vec![0xca, 0xfe], checksum: [0x0a, 0x0b] });
+
+    assert_eq!(
+        Packet::validate_json(&json!({ "payload": "abc", "checksum": "0000" })),
+        Err(JsonableError::InvalidMapValue {
+            key: "payload".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<Vec<u8>>(),
+                error: Box::from(JsonableError::InvalidFormat { expected: "hex string", got: "abc".into() })
+            })
+        })
+    );
+    assert!(Packet::validate_json(&json!({ "payload": "zz", "checksum": "0000" })).is_err());
+    assert_eq!(
+        Packet::validate_json(&json!({ "payload": "", "checksum": "000000" })),
+        Err(JsonableError::InvalidMapValue {
+            key: "checksum".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<[u8; 2]>(),
+                error: Box::from(JsonableError::InvalidArrayLength { got: 3, expected: 2 })
+            })
+        })
+    );
+}
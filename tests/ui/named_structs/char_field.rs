@@ -0,0 +1,25 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Csv {
+    pub delimiter: char,
+    pub quote: Option<char>
+}
+
+fn main() {
+    let csv = Csv::from_json(json!({ "delimiter": "€", "quote": null })).unwrap();
+    assert_eq!(csv, Csv { delimiter: '€', quote: None });
+    assert_eq!(csv.to_json(), json!({ "delimiter": "€", "quote": null }));
+
+    assert_eq!(
+        Csv::validate_json(&json!({ "delimiter": "", "quote": null })),
+        Err(JsonableError::InvalidMapValue {
+            key: "delimiter".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: "char",
+                error: Box::from(JsonableError::InvalidFormat { expected: "single character string", got: "".into() })
+            })
+        })
+    );
+}
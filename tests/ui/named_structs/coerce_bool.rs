@@ -0,0 +1,38 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Flags {
+    #[jsonable(coerce_bool)]
+    pub enabled: bool
+}
+
+fn main() {
+    for (json, expected) in [
+        (json!(true), true),
+        (json!(false), false),
+        (json!(1), true),
+        (json!(0), false),
+        (json!("true"), true),
+        (json!("FALSE"), false),
+        (json!("Yes"), true),
+        (json!("no"), false),
+    ] {
+        let flags = Flags::from_json(json!({ "enabled": json })).unwrap();
+        assert_eq!(flags.enabled, expected);
+        assert_eq!(flags.to_json(), json!({ "enabled": expected }));
+    }
+
+    assert_eq!(
+        Flags::validate_json(&json!({ "enabled": 2 })),
+        Err(JsonableError::InvalidMapValue {
+            key: "enabled".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: "bool",
+                error: Box::from(JsonableError::InvalidFormat { expected: "true/false, 1/0, or yes/no", got: "2".into() })
+            })
+        })
+    );
+    assert!(Flags::validate_json(&json!({ "enabled": "on" })).is_err());
+    assert!(Flags::validate_json(&json!({ "enabled": null })).is_err());
+}
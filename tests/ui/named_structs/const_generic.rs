@@ -0,0 +1,23 @@
+use std::borrow::Cow;
+
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Holder<'a, const N: usize> {
+    pub name: Cow<'a, str>,
+    pub tag: [u8; N],
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Pair<'a, T, const N: usize>(Cow<'a, str>, [T; N]);
+
+fn main() {
+    let holder = Holder::<2>::from_json(json!({ "name": "pair", "tag": [1, 2] })).unwrap();
+    assert_eq!(holder, Holder { name: "pair".into(), tag: [1, 2] });
+    assert_eq!(holder.to_json(), json!({ "name": "pair", "tag": [1, 2] }));
+    assert!(Holder::<3>::validate_json(&json!({ "name": "pair", "tag": [1, 2] })).is_err());
+
+    let pair = Pair::<u8, 2>::from_json(json!(["pair", [1, 2]])).unwrap();
+    assert_eq!(pair, Pair("pair".into(), [1, 2]));
+}
@@ -0,0 +1,31 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Post {
+    #[jsonable(dedup)]
+    pub tags: Vec<String>,
+    #[jsonable(dedup)]
+    pub scores: Vec<u8>
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Grid {
+    #[jsonable(dedup)]
+    pub rows: Vec<Vec<u8>>
+}
+
+fn main() {
+    let json = json!({ "tags": ["rust", "json", "rust", "serde", "json"], "scores": [3, 1, 3, 2, 1] });
+    assert!(Post::validate_json(&json).is_ok());
+
+    let post = Post::from_json(json).unwrap();
+    assert_eq!(post.tags, vec!["rust", "json", "serde"]);
+    assert_eq!(post.scores, vec![3, 1, 2]);
+    assert_eq!(post.to_json(), json!({ "tags": ["rust", "json", "serde"], "scores": [3, 1, 2] }));
+
+    assert!(Post::validate_json(&json!({ "tags": [1], "scores": [] })).is_err());
+
+    let grid = Grid::from_json(json!({ "rows": [[1, 2], [2, 1], [1, 2], []] })).unwrap();
+    assert_eq!(grid.rows, vec![vec![1, 2], vec![2, 1], vec![]]);
+}
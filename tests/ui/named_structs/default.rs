@@ -0,0 +1,31 @@
+use jsonable::*;
+use serde_json::json;
+
+fn default_retries() -> u8 {
+    3
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Counter {
+    #[jsonable(default)]
+    pub count: u32,
+    #[jsonable(default = "default_retries")]
+    pub retries: u8,
+    #[jsonable(default)]
+    pub tags: Vec<String>
+}
+
+fn main() {
+    let empty = Counter { count: 0, retries: 3, tags: vec![] };
+    assert_eq!(Counter::from_json(json!({})), Ok(empty));
+    assert_eq!(Counter::from_json_fused(json!({})), Ok(Counter { count: 0, retries: 3, tags: vec![] }));
+
+    let json = json!({ "count": 5, "retries": 1, "tags": ["a"] });
+    assert_eq!(Counter::from_json(json.clone()).unwrap().to_json(), json);
+    assert_eq!(Counter::from_json_fused(json.clone()).unwrap().to_json(), json);
+
+    // A present key is still checked against the field's type
+    assert!(Counter::validate_json(&json!({ "count": "five" })).is_err());
+    assert!(Counter::validate_json(&json!({ "count": null })).is_err());
+    assert!(Counter::from_json_fused(json!({ "retries": -1 })).is_err());
+}
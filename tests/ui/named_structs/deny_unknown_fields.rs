@@ -0,0 +1,40 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Lenient {
+    pub something: u8,
+    pub value: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Address {
+    pub city: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(deny_unknown_fields)]
+struct Strict {
+    pub something: u8,
+    pub value: String,
+    #[jsonable(flatten, prefix = "addr_")]
+    pub address: Address
+}
+
+fn main() {
+    let json = json!({ "something": 1, "value": "x", "addr_city": "Oslo", "typo": true });
+
+    assert!(Lenient::validate_json(&json).is_ok());
+
+    let unknown = || JsonableError::UnknownField { ty: "Strict", field: "typo".into() };
+    assert_eq!(Strict::validate_json(&json), Err(unknown()));
+    assert_eq!(Strict::from_json(json.clone()), Err(unknown()));
+    assert_eq!(Strict::from_json_fused(json), Err(unknown()));
+
+    let valid = json!({ "something": 1, "value": "x", "addr_city": "Oslo" });
+    assert_eq!(Strict::from_json_fused(valid.clone()).unwrap().to_json(), valid);
+    assert_eq!(
+        Strict::from_json(valid),
+        Ok(Strict { something: 1, value: "x".into(), address: Address { city: "Oslo".into() } })
+    );
+}
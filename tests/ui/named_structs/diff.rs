@@ -0,0 +1,24 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Jsonable)]
+struct Person {
+    pub first_name: String,
+    pub last_name: Option<String>,
+}
+
+fn main() {
+    let before = Person { first_name: "Andrew".into(), last_name: Some("Smith".into()) };
+    let after = Person { first_name: "Andrew".into(), last_name: Some("Jones".into()) };
+
+    assert_eq!(
+        before.diff(&after),
+        json!([{ "op": "replace", "path": "/last_name", "value": "Jones" }])
+    );
+    assert_eq!(before.diff(&before), json!([]));
+
+    let mut patched = before.to_json();
+    let patch: json_patch::Patch = serde_json::from_value(before.diff(&after)).unwrap();
+    json_patch::patch(&mut patched, &patch).unwrap();
+    assert_eq!(patched, after.to_json());
+}
@@ -0,0 +1,28 @@
+use jsonable::*;
+use serde_json::json;
+
+use std::cell::Cell;
+
+thread_local! {
+    static DROPPED: Cell<u32> = const { Cell::new(0) };
+}
+
+// Without `#[jsonable(into_json)]` no field is moved out, so types implementing `Drop` derive
+#[derive(Debug, PartialEq, Jsonable)]
+struct Guard {
+    pub name: String
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        DROPPED.with(|dropped| dropped.set(dropped.get() + 1));
+    }
+}
+
+fn main() {
+    let guard = Guard { name: "lock".into() };
+    assert_eq!(guard.into_json(), json!({ "name": "lock" }));
+    assert_eq!(DROPPED.with(Cell::get), 1);
+
+    assert_eq!(Guard::from_json(json!({ "name": "lock" })).unwrap().name, "lock");
+}
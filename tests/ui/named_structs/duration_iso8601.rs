@@ -0,0 +1,26 @@
+use jsonable::*;
+use serde_json::json;
+
+use std::time::Duration;
+
+#[derive(Jsonable)]
+struct Timeouts {
+    #[jsonable(duration = "iso8601")]
+    pub connect: Duration,
+    pub retries: u8
+}
+
+fn main() {
+    let timeouts = Timeouts::from_json(json!({ "connect": "PT1H30M", "retries": 3 })).unwrap();
+    assert_eq!(timeouts.connect, Duration::from_secs(5400));
+    assert_eq!(timeouts.to_json(), json!({ "connect": "PT1H30M", "retries": 3 }));
+
+    let result = Timeouts::validate_json(&json!({ "connect": "1 hour", "retries": 3 }));
+    assert_eq!(result, Err(JsonableError::InvalidMapValue {
+        key: "connect".into(),
+        error: Box::from(JsonableError::InnerErrorForType {
+            ty: std::any::type_name::<Duration>(),
+            error: Box::from(JsonableError::InvalidFormat { expected: "ISO 8601 duration", got: "1 hour".into() })
+        })
+    }));
+}
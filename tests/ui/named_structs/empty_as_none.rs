@@ -0,0 +1,32 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Inner {
+    pub id: u32
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Response {
+    #[jsonable(empty_as_none)]
+    pub inner: Option<Inner>,
+    #[jsonable(empty_as_none)]
+    pub ids: Option<Vec<u32>>
+}
+
+fn main() {
+    let empty_object = Response::from_json(json!({ "inner": {}, "ids": null })).unwrap();
+    assert_eq!(empty_object, Response { inner: None, ids: None });
+    assert_eq!(empty_object.to_json(), json!({ "inner": null, "ids": null }));
+
+    let empty_array = Response::from_json(json!({ "inner": [], "ids": [] })).unwrap();
+    assert_eq!(empty_array, Response { inner: None, ids: None });
+
+    let absent = Response::from_json(json!({})).unwrap();
+    assert_eq!(absent, Response { inner: None, ids: None });
+
+    let present = Response::from_json(json!({ "inner": { "id": 1 }, "ids": [2] })).unwrap();
+    assert_eq!(present, Response { inner: Some(Inner { id: 1 }), ids: Some(vec![2]) });
+
+    assert!(Response::validate_json(&json!({ "inner": { "other": 1 }, "ids": [] })).is_err());
+}
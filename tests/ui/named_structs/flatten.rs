@@ -0,0 +1,77 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Address {
+    pub street: String,
+    pub city: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Audit {
+    pub created_by: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Metadata {
+    pub created: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Post {
+    pub title: String,
+    #[jsonable(flatten)]
+    pub metadata: Metadata
+}
+
+/// Flattens a type that is not written as an object
+#[derive(Debug, PartialEq, Jsonable)]
+struct Tagged {
+    #[jsonable(flatten)]
+    pub tags: Vec<String>
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct User {
+    pub name: String,
+    #[jsonable(flatten, prefix = "addr_")]
+    pub address: Address,
+    #[jsonable(flatten)]
+    pub audit: Audit
+}
+
+fn main() {
+    let json = json!({
+        "name": "Ada",
+        "addr_street": "1 Main St",
+        "addr_city": "London",
+        "created_by": "admin"
+    });
+
+    let user = User {
+        name: "Ada".into(),
+        address: Address { street: "1 Main St".into(), city: "London".into() },
+        audit: Audit { created_by: "admin".into() }
+    };
+
+    assert_eq!(User::from_json(json.clone()), Ok(user));
+    assert_eq!(User::from_json_fused(json.clone()).unwrap().to_json(), json);
+    assert_eq!(User::from_json(json.clone()).unwrap().to_json(), json);
+
+    let missing_city = json!({
+        "name": "Ada",
+        "addr_street": "1 Main St",
+        "city": "London",
+        "created_by": "admin"
+    });
+    assert!(User::validate_json(&missing_city).is_err());
+    assert!(User::from_json_fused(missing_city).is_err());
+
+    let post = Post { title: "Hello".into(), metadata: Metadata { created: "2024-01-01".into() } };
+    let json = json!({ "title": "Hello", "created": "2024-01-01" });
+    assert_eq!(post.to_json(), json);
+    assert_eq!(Post::from_json(json), Ok(post));
+
+    assert!(Tagged::validate_json(&json!({ "tags": ["a"] })).is_err());
+    assert!(std::panic::catch_unwind(|| Tagged { tags: vec![] }.to_json()).is_err());
+}
@@ -0,0 +1,37 @@
+use jsonable::*;
+use serde_json::{json, Value};
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Inner {
+    pub id: u32,
+    pub tags: Vec<String>
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Outer {
+    pub name: String,
+    pub inner: Option<Inner>,
+    #[jsonable(items(max = 2))]
+    pub scores: Vec<u8>
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Pair(u8, Inner);
+
+fn same_as_from_json<T: Jsonable + std::fmt::Debug + PartialEq>(json: Value) {
+    assert_eq!(T::from_json_fused(json.clone()), T::from_json(json));
+}
+
+fn main() {
+    let outer = Outer::from_json_fused(json!({ "name": "a", "inner": { "id": 1, "tags": ["x"] }, "scores": [1] })).unwrap();
+    assert_eq!(outer, Outer { name: "a".into(), inner: Some(Inner { id: 1, tags: vec!["x".into()] }), scores: vec![1] });
+
+    same_as_from_json::<Outer>(json!({ "name": "a", "inner": null, "scores": [] }));
+    same_as_from_json::<Outer>(json!({ "name": 1, "inner": null, "scores": [] }));
+    same_as_from_json::<Outer>(json!({ "name": "a", "inner": { "id": 1, "tags": [1] }, "scores": [] }));
+    same_as_from_json::<Outer>(json!({ "name": "a", "inner": null, "scores": [1, 2, 3] }));
+    same_as_from_json::<Outer>(json!([]));
+
+    same_as_from_json::<Pair>(json!([1, { "id": 2, "tags": [] }]));
+    same_as_from_json::<Pair>(json!([1, { "id": -2, "tags": [] }]));
+}
@@ -0,0 +1,77 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(generate_patch)]
+pub struct User {
+    pub name: String,
+    pub age: u8,
+    pub nickname: Option<String>
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(generate_patch)]
+struct Wrapper<T> {
+    pub value: T,
+    pub label: String
+}
+
+fn main() {
+    let mut user = User { name: "Ada".into(), age: 36, nickname: None };
+
+    let patch = UserPatch::from_json(json!({ "age": 37, "nickname": "Countess" })).unwrap();
+    assert_eq!(patch.name, None);
+    assert_eq!(patch.to_json(), json!({ "age": 37, "nickname": "Countess" }));
+
+    patch.apply(&mut user);
+    assert_eq!(user, User { name: "Ada".into(), age: 37, nickname: Some("Countess".into()) });
+
+    // Absent keys, and null for a field rejecting it, leave the field untouched
+    UserPatch::from_json(json!({ "name": null })).unwrap().apply(&mut user);
+    assert_eq!(user.name, "Ada");
+    assert_eq!(user.nickname, Some("Countess".into()));
+
+    // Null clears an optional field
+    let clear = UserPatch::from_json(json!({ "nickname": null })).unwrap();
+    assert_eq!(clear.nickname, Some(None));
+    assert_eq!(clear.to_json(), json!({ "nickname": null }));
+    clear.apply(&mut user);
+    assert_eq!(user, User { name: "Ada".into(), age: 37, nickname: None });
+
+    assert_eq!(
+        UserPatch::validate_json(&json!({ "age": "old" })),
+        Err(JsonableError::InvalidMapValue {
+            key: "age".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: "u8",
+                error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+            })
+        })
+    );
+    // Every bad field is reported
+    match UserPatch::validate_json(&json!({ "name": 1, "age": "old" })) {
+        Err(JsonableError::InnerErrorsForType { ty, errors }) => {
+            assert_eq!(ty, "UserPatch");
+            assert_eq!(errors.len(), 2);
+        }
+        other => panic!("Unexpected result {:?}", other)
+    }
+    assert_eq!(UserPatch::default().to_json(), json!({}));
+
+    // Every field is optional, and may be null
+    assert_eq!(
+        UserPatch::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+                "age": { "anyOf": [{ "type": "integer", "minimum": 0, "maximum": 255 }, { "type": "null" }] },
+                "nickname": { "anyOf": [{ "type": "string" }, { "type": "null" }] }
+            }
+        })
+    );
+
+    let mut wrapper = Wrapper { value: 1u32, label: "one".into() };
+    WrapperPatch::<u32>::from_json(json!({ "value": 2 })).unwrap().apply(&mut wrapper);
+    assert_eq!(wrapper, Wrapper { value: 2, label: "one".into() });
+}
@@ -0,0 +1,14 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Page<T> {
+    pub items: Vec<T>,
+    pub next: Option<String>
+}
+
+fn main() {
+    let page = Page::<u8>::from_json(json!({ "items": [1, 2, 3], "next": null })).unwrap();
+    assert_eq!(page, Page { items: vec![1, 2, 3], next: None });
+    assert_eq!(page.to_json(), json!({ "items": [1, 2, 3], "next": null }));
+}
@@ -0,0 +1,55 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq, Jsonable)]
+#[jsonable(into_json)]
+struct Address {
+    pub street: String,
+    pub city: String
+}
+
+#[derive(Debug, Clone, PartialEq, Jsonable)]
+#[jsonable(into_json)]
+struct Document {
+    pub title: String,
+    pub tags: Vec<String>,
+    #[jsonable(as_hex)]
+    pub checksum: Vec<u8>,
+    #[jsonable(flatten, prefix = "address_")]
+    pub address: Address,
+    #[jsonable(skip)]
+    pub cached: Option<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Jsonable)]
+#[jsonable(into_json)]
+struct Pair(String, Option<u8>);
+
+#[derive(Debug, Clone, PartialEq, Jsonable)]
+#[jsonable(transparent, into_json)]
+struct Name(String);
+
+fn main() {
+    let document = Document {
+        title: "Notes".into(),
+        tags: vec!["a".into(), "b".into()],
+        checksum: vec![0x0a, 0xff],
+        address: Address { street: "Main".into(), city: "Oslo".into() },
+        cached: Some("ignored".into())
+    };
+
+    let expected = json!({
+        "title": "Notes",
+        "tags": ["a", "b"],
+        "checksum": "0aff",
+        "address_street": "Main",
+        "address_city": "Oslo"
+    });
+    assert_eq!(document.to_json(), expected);
+    assert_eq!(document.into_json(), expected);
+
+    let pair = Pair("left".into(), None);
+    assert_eq!(pair.clone().into_json(), pair.to_json());
+
+    assert_eq!(Name("Ada".into()).into_json(), json!("Ada"));
+}
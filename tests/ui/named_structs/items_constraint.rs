@@ -0,0 +1,27 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, Jsonable)]
+struct Tags {
+    #[jsonable(items(min = 1, max = 3, unique))]
+    pub values: Vec<String>
+}
+
+fn inner_error(json: serde_json::Value) -> JsonableError {
+    match Tags::validate_json(&json) {
+        Err(JsonableError::InvalidMapValue { key, error }) if key == "values" => match *error {
+            JsonableError::InnerErrorForType { error, .. } => *error,
+            other => panic!("Expected an inner error, got {:?}", other)
+        },
+        other => panic!("Expected an error under `values`, got {:?}", other)
+    }
+}
+
+fn main() {
+    let tags = Tags::from_json(json!({ "values": ["a", "b"] })).unwrap();
+    assert_eq!(tags.values, vec!["a", "b"]);
+
+    assert_eq!(inner_error(json!({ "values": [] })), JsonableError::CollectionTooShort { got: 0, min: 1 });
+    assert_eq!(inner_error(json!({ "values": ["a", "b", "c", "d"] })), JsonableError::CollectionTooLong { got: 4, max: 3 });
+    assert_eq!(inner_error(json!({ "values": ["a", "b", "a"] })), JsonableError::DuplicateElement { index: 2 });
+}
@@ -0,0 +1,52 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Jsonable)]
+struct Person {
+    pub first_name: String,
+    pub last_name: Option<String>,
+    #[jsonable(default)]
+    pub age: u8,
+}
+
+#[derive(Jsonable)]
+#[jsonable(deny_unknown_fields)]
+struct Account {
+    pub id: u64,
+    #[jsonable(flatten, prefix = "owner_")]
+    pub owner: Person,
+    #[jsonable(skip)]
+    pub cache: Vec<u8>,
+}
+
+#[derive(Jsonable)]
+struct Team {
+    pub lead: Person,
+    pub members: Vec<Person>,
+}
+
+fn main() {
+    let schema = Person::json_schema();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["required"], json!(["first_name"]));
+    assert_eq!(schema["properties"]["first_name"], json!({ "type": "string" }));
+    assert_eq!(
+        schema["properties"]["last_name"],
+        json!({ "anyOf": [{ "type": "string" }, { "type": "null" }] })
+    );
+
+    let schema = Account::json_schema();
+    assert_eq!(schema["additionalProperties"], false);
+    assert_eq!(schema["required"], json!(["id", "owner_first_name"]));
+    let mut keys: Vec<&String> = schema["properties"].as_object().unwrap().keys().collect();
+    keys.sort();
+    assert_eq!(keys, ["id", "owner_age", "owner_first_name", "owner_last_name"]);
+    assert_eq!(schema.get("$defs"), None);
+
+    // Nested derived types are referenced, and described once under `$defs`
+    let schema = Team::json_schema();
+    let person_ref = json!({ "$ref": "#/$defs/Person" });
+    assert_eq!(schema["properties"]["lead"], person_ref);
+    assert_eq!(schema["properties"]["members"], json!({ "type": "array", "items": person_ref }));
+    assert_eq!(schema["$defs"], json!({ "Person": Person::json_schema() }));
+}
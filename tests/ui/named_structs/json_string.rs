@@ -0,0 +1,25 @@
+use jsonable::*;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Person {
+    pub name: String,
+    pub age: u8,
+    pub nickname: Option<String>
+}
+
+fn main() {
+    let person = Person { name: "Ada".into(), age: 36, nickname: None };
+
+    let text = person.to_json_string();
+    assert_eq!(Person::from_json_str(&text), Ok(person));
+
+    let pretty = Person::from_json_str(r#"{ "name": "Ada", "age": 36 }"#).unwrap().to_json_string_pretty();
+    assert!(pretty.contains('\n'));
+    assert_eq!(Person::from_json_str(&pretty).unwrap().age, 36);
+
+    assert!(matches!(Person::from_json_str(r#"{ "name": "Ada", "#), Err(JsonableError::Parse(_))));
+    assert!(matches!(
+        Person::from_json_str(r#"{ "name": "Ada", "age": "old" }"#),
+        Err(JsonableError::InvalidMapValue { ref key, .. }) if key == "age"
+    ));
+}
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Config {
+    #[jsonable(lowercase_keys)]
+    pub settings: HashMap<String, String>
+}
+
+fn main() {
+    let config = Config::from_json(json!({ "settings": { "Host": "localhost", "PORT": "8080" } })).unwrap();
+
+    let mut keys: Vec<&String> = config.settings.keys().collect();
+    keys.sort();
+    assert_eq!(keys, vec!["host", "port"]);
+    assert_eq!(config.settings["host"], "localhost");
+    assert_eq!(config.to_json(), json!({ "settings": { "host": "localhost", "port": "8080" } }));
+
+    assert_eq!(
+        Config::validate_json(&json!({ "settings": { "Host": "a", "host": "b" } })),
+        Err(JsonableError::InvalidMapValue {
+            key: "settings".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<HashMap<String, String>>(),
+                error: Box::from(JsonableError::DuplicateKey { key: "host".into() })
+            })
+        })
+    );
+    assert!(Config::validate_json(&json!({ "settings": { "Host": 1 } })).is_err());
+}
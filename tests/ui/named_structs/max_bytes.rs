@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, Jsonable)]
+struct Upload {
+    pub name: String,
+    // {"a":"xxxx"} is 12 bytes
+    #[jsonable(max_bytes = 12)]
+    pub metadata: HashMap<String, String>
+}
+
+fn main() {
+    let upload = Upload::from_json(json!({ "name": "small", "metadata": { "a": "xxxx" } })).unwrap();
+    assert_eq!(upload.metadata["a"], "xxxx");
+
+    assert_eq!(
+        Upload::validate_json(&json!({ "name": "large", "metadata": { "a": "xxxx", "b": "y" } })),
+        Err(JsonableError::InvalidMapValue {
+            key: "metadata".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<HashMap<String, String>>(),
+                error: Box::from(JsonableError::PayloadTooLarge { got: 20, max: 12 })
+            })
+        })
+    );
+}
@@ -0,0 +1,36 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Address {
+    pub city: String,
+    pub street: String,
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Person {
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub address: Address,
+}
+
+fn main() {
+    let mut person = Person {
+        first_name: "Andrew".into(),
+        last_name: None,
+        address: Address { city: "Paris".into(), street: "Rue de Rivoli".into() },
+    };
+
+    person.merge_json(json!({ "last_name": "X" })).unwrap();
+    assert_eq!(person.first_name, "Andrew");
+    assert_eq!(person.last_name, Some("X".into()));
+
+    person.merge_json(json!({ "address": { "street": "Champs-Élysées" } })).unwrap();
+    assert_eq!(person.address, Address { city: "Paris".into(), street: "Champs-Élysées".into() });
+
+    person.merge_json(json!({ "last_name": null })).unwrap();
+    assert_eq!(person.last_name, None);
+
+    assert!(person.merge_json(json!({ "first_name": 1 })).is_err());
+    assert_eq!(person.first_name, "Andrew");
+}
@@ -0,0 +1,10 @@
+use jsonable::*;
+
+#[derive(Jsonable)]
+struct Upload {
+    pub name: String,
+    #[jsonable(as_hex, dedup, required)]
+    pub bytes: Vec<u8>
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Form {
+    pub name: String,
+    pub age: u8,
+    pub email: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Empty {}
+
+fn main() {
+    let json = json!({ "name": 7, "age": 30, "email": false });
+    let expected = || JsonableError::InnerErrorsForType {
+        ty: "Form",
+        errors: vec![
+            JsonableError::InvalidMapValue {
+                key: "name".into(),
+                error: Box::from(JsonableError::InnerErrorForType {
+                    ty: std::any::type_name::<String>(),
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "number", expected: "string" })
+                })
+            },
+            JsonableError::InvalidMapValue {
+                key: "email".into(),
+                error: Box::from(JsonableError::InnerErrorForType {
+                    ty: std::any::type_name::<String>(),
+                    error: Box::from(JsonableError::IncompatibleJsonType { got: "bool", expected: "string" })
+                })
+            }
+        ]
+    };
+    assert_eq!(Form::validate_json(&json), Err(expected()));
+    assert_eq!(Form::from_json(json.clone()), Err(expected()));
+    assert_eq!(Form::from_json_fused(json), Err(expected()));
+
+    // A single bad field is reported on its own, still under its key
+    let single = json!({ "name": "Ada", "age": "old", "email": "ada@example.com" });
+    let expected = || JsonableError::InvalidMapValue {
+        key: "age".into(),
+        error: Box::from(JsonableError::InnerErrorForType {
+            ty: "u8",
+            error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+        })
+    };
+    assert_eq!(Form::validate_json(&single), Err(expected()));
+    assert_eq!(Form::from_json_fused(single), Err(expected()));
+
+    assert_eq!(Empty::from_json(json!({})), Ok(Empty {}));
+    assert_eq!(Empty::from_json_fused(json!({ "extra": 1 })), Ok(Empty {}));
+}
@@ -0,0 +1,24 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Order {
+    pub id: u32,
+    #[jsonable(null_as_empty)]
+    pub items: Vec<String>
+}
+
+fn main() {
+    let null = Order::from_json(json!({ "id": 1, "items": null })).unwrap();
+    assert_eq!(null, Order { id: 1, items: vec![] });
+    assert_eq!(null.to_json(), json!({ "id": 1, "items": [] }));
+
+    let absent = Order::from_json(json!({ "id": 2 })).unwrap();
+    assert_eq!(absent, Order { id: 2, items: vec![] });
+
+    let present = Order::from_json(json!({ "id": 3, "items": ["tea", "cake"] })).unwrap();
+    assert_eq!(present, Order { id: 3, items: vec!["tea".into(), "cake".into()] });
+    assert_eq!(present.to_json(), json!({ "id": 3, "items": ["tea", "cake"] }));
+
+    assert!(Order::validate_json(&json!({ "id": 4, "items": [1] })).is_err());
+}
@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Request {
+    pub url: String,
+    #[jsonable(null_as_zero)]
+    pub timeout: Duration
+}
+
+fn main() {
+    let null = Request::from_json(json!({ "url": "/", "timeout": null })).unwrap();
+    assert_eq!(null.timeout, Duration::ZERO);
+    assert_eq!(null.to_json(), json!({ "url": "/", "timeout": { "secs": 0, "nanos": 0 } }));
+
+    let absent = Request::from_json(json!({ "url": "/" })).unwrap();
+    assert_eq!(absent.timeout, Duration::ZERO);
+
+    let present = Request::from_json(json!({ "url": "/", "timeout": { "secs": 5, "nanos": 0 } })).unwrap();
+    assert_eq!(present.timeout, Duration::from_secs(5));
+
+    assert!(Request::validate_json(&json!({ "url": "/", "timeout": 5 })).is_err());
+}
@@ -0,0 +1,37 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Listing {
+    #[jsonable(object_as_vec)]
+    pub items: Vec<String>
+}
+
+fn main() {
+    let from_array = Listing::from_json(json!({ "items": ["a", "b", "c"] })).unwrap();
+    assert_eq!(from_array.items, vec!["a", "b", "c"]);
+
+    // Keys are ordered numerically, not as strings
+    let from_object = Listing::from_json(json!({ "items": { "10": "c", "2": "b", "1": "a" } })).unwrap();
+    assert_eq!(from_object, from_array);
+    assert_eq!(from_object.to_json(), json!({ "items": ["a", "b", "c"] }));
+
+    let items_error = |error: JsonableError| JsonableError::InvalidMapValue {
+        key: "items".into(),
+        error: Box::from(JsonableError::InnerErrorForType {
+            ty: std::any::type_name::<Vec<String>>(),
+            error: Box::from(error)
+        })
+    };
+    assert_eq!(
+        Listing::validate_json(&json!({ "items": { "0": "a", "first": "b" } })),
+        Err(items_error(JsonableError::InvalidFormat { expected: "non-negative integer key", got: "first".into() }))
+    );
+    assert_eq!(
+        Listing::validate_json(&json!({ "items": { "0": "a", "1": 2 } })),
+        Err(items_error(JsonableError::InvalidMapValue {
+            key: "1".into(),
+            error: Box::from(JsonableError::IncompatibleJsonType { got: "number", expected: "string" })
+        }))
+    );
+}
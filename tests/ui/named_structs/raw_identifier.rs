@@ -0,0 +1,27 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(generate_patch)]
+struct Token {
+    pub r#type: String,
+    pub r#match: Option<u8>
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+enum Node {
+    Leaf { r#type: String }
+}
+
+fn main() {
+    let token = Token::from_json(json!({ "type": "word", "match": 1 })).unwrap();
+    assert_eq!(token, Token { r#type: "word".into(), r#match: Some(1) });
+    assert_eq!(token.to_json(), json!({ "type": "word", "match": 1 }));
+
+    let patch = TokenPatch::from_json(json!({ "type": "number" })).unwrap();
+    assert_eq!(patch.to_json(), json!({ "type": "number" }));
+
+    let leaf = Node::Leaf { r#type: "x".into() };
+    assert_eq!(leaf.to_json(), json!({ "Leaf": { "type": "x" } }));
+    assert_eq!(Node::from_json(leaf.to_json()), Ok(leaf));
+}
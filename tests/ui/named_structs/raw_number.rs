@@ -0,0 +1,17 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Balance {
+    pub account: String,
+    pub amount: RawNumber,
+}
+
+fn main() {
+    let json = json!({ "account": "savings", "amount": 12345678901234567890u64 });
+    let balance = Balance::from_json(json.clone()).unwrap();
+
+    assert_eq!(balance.amount.to_string(), "12345678901234567890");
+    assert_eq!(balance.to_json(), json);
+    assert!(Balance::from_json(json!({ "account": "savings", "amount": "12" })).is_err());
+}
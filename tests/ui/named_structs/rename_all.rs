@@ -0,0 +1,46 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(rename_all = "camelCase", generate_patch)]
+struct Person {
+    pub first_name: String,
+    pub last_name: String,
+    #[jsonable(rename = "years")]
+    pub age_in_years: u8
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(rename_all = "SCREAMING_SNAKE_CASE")]
+struct Env {
+    pub home_dir: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(rename_all = "kebab-case")]
+struct Header {
+    pub content_type: String
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(rename_all = "PascalCase")]
+struct Pascal {
+    pub user_id: u8
+}
+
+fn main() {
+    let json = json!({ "firstName": "Ada", "lastName": "Lovelace", "years": 36 });
+    let person = Person { first_name: "Ada".into(), last_name: "Lovelace".into(), age_in_years: 36 };
+
+    assert_eq!(Person::from_json(json.clone()), Ok(person));
+    assert_eq!(Person::from_json_fused(json.clone()).unwrap().to_json(), json);
+    assert!(Person::validate_json(&json!({ "first_name": "Ada", "lastName": "Lovelace", "years": 36 })).is_err());
+
+    let patch = PersonPatch::from_json(json!({ "lastName": "Byron" })).unwrap();
+    assert_eq!(patch.last_name, Some("Byron".into()));
+    assert_eq!(patch.to_json(), json!({ "lastName": "Byron" }));
+
+    assert_eq!(Env { home_dir: "/root".into() }.to_json(), json!({ "HOME_DIR": "/root" }));
+    assert_eq!(Header { content_type: "text/plain".into() }.to_json(), json!({ "content-type": "text/plain" }));
+    assert_eq!(Pascal::from_json(json!({ "UserId": 1 })), Ok(Pascal { user_id: 1 }));
+}
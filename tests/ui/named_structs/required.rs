@@ -0,0 +1,27 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Account {
+    #[jsonable(required)]
+    pub email: Option<String>,
+    pub nickname: Option<String>
+}
+
+fn main() {
+    let account = Account::from_json(json!({ "email": "a@b.c" })).unwrap();
+    assert_eq!(account, Account { email: Some("a@b.c".into()), nickname: None });
+    assert_eq!(account.to_json(), json!({ "email": "a@b.c", "nickname": null }));
+
+    let missing = || JsonableError::InvalidMapValue {
+        key: "email".into(),
+        error: Box::from(JsonableError::InnerErrorForType {
+            ty: std::any::type_name::<Option<String>>(),
+            error: Box::from(JsonableError::MissingField)
+        })
+    };
+    assert_eq!(Account::validate_json(&json!({ "email": null })), Err(missing()));
+    assert_eq!(Account::from_json(json!({ "nickname": "ab" })), Err(missing()));
+
+    assert!(Account::validate_json(&json!({ "email": 1 })).is_err());
+}
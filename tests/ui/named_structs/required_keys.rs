@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, Jsonable)]
+struct Config {
+    #[jsonable(required_keys = ["host", "port"])]
+    pub server: HashMap<String, String>
+}
+
+fn main() {
+    let config = Config::from_json(json!({ "server": { "host": "localhost", "port": "8080", "scheme": "http" } })).unwrap();
+    assert_eq!(config.server["host"], "localhost");
+    assert_eq!(config.server.len(), 3);
+
+    assert_eq!(
+        Config::validate_json(&json!({ "server": { "host": "localhost" } })),
+        Err(JsonableError::InvalidMapValue {
+            key: "server".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<HashMap<String, String>>(),
+                error: Box::from(JsonableError::MissingRequiredKey { key: "port".into() })
+            })
+        })
+    );
+}
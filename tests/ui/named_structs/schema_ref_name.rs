@@ -0,0 +1,62 @@
+use jsonable::*;
+use serde_json::{json, Value};
+
+#[derive(Jsonable)]
+struct Address {
+    pub city: String
+}
+
+#[derive(Jsonable)]
+#[jsonable(generate_patch)]
+struct User {
+    pub name: String,
+    pub address: Address
+}
+
+#[derive(Jsonable)]
+enum Role {
+    Admin,
+    Guest
+}
+
+#[derive(Jsonable)]
+struct Pair<T> {
+    pub left: T,
+    pub right: T
+}
+
+#[derive(Jsonable)]
+struct Borrowed<'a> {
+    pub name: std::borrow::Cow<'a, str>
+}
+
+#[derive(Jsonable)]
+struct r#Type {
+    pub value: u8
+}
+
+/// `$ref` to the type's reusable schema, if it has one
+fn reference<T: Jsonable>() -> Option<Value> {
+    T::schema_ref_name().map(|name| json!({ "$ref": format!("#/components/schemas/{}", name) }))
+}
+
+fn main() {
+    assert_eq!(User::schema_ref_name(), Some("User"));
+    assert_eq!(UserPatch::schema_ref_name(), Some("UserPatch"));
+    assert_eq!(Role::schema_ref_name(), Some("Role"));
+    assert_eq!(Type::schema_ref_name(), Some("Type"));
+
+    assert_eq!(reference::<Address>(), Some(json!({ "$ref": "#/components/schemas/Address" })));
+
+    assert_eq!(<Box<Address>>::schema_ref_name(), Some("Address"));
+
+    // Each instantiation of a generic type has its own schema, so none of them is named
+    assert_eq!(Pair::<u8>::schema_ref_name(), None);
+    assert_eq!(Pair::<String>::schema_ref_name(), None);
+    assert_eq!(Borrowed::schema_ref_name(), Some("Borrowed"));
+
+    assert_eq!(u8::schema_ref_name(), None);
+    assert_eq!(String::schema_ref_name(), None);
+    assert_eq!(<Vec<Address>>::schema_ref_name(), None);
+    assert_eq!(reference::<String>(), None);
+}
@@ -0,0 +1,35 @@
+use jsonable::*;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Not `Jsonable`, only ever built at runtime
+#[derive(Debug, Default, PartialEq)]
+struct Handle(u32);
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(deny_unknown_fields, generate_patch)]
+struct Session {
+    pub user: String,
+    #[jsonable(skip)]
+    pub handle: Handle,
+    #[jsonable(skip)]
+    pub cache: HashMap<String, String>
+}
+
+fn main() {
+    let mut session = Session { user: "ada".into(), handle: Handle(7), cache: HashMap::new() };
+    session.cache.insert("a".into(), "b".into());
+    assert_eq!(session.to_json(), json!({ "user": "ada" }));
+
+    let expected = Session { user: "ada".into(), handle: Handle(0), cache: HashMap::new() };
+    assert_eq!(Session::from_json(json!({ "user": "ada" })), Ok(expected));
+    assert_eq!(Session::from_json_fused(json!({ "user": "ada" })).unwrap().handle, Handle(0));
+
+    // The skipped field's key is not one of the struct's keys
+    assert!(Session::validate_json(&json!({ "user": "ada", "handle": 1 })).is_err());
+
+    let patch = SessionPatch::from_json(json!({ "user": "grace" })).unwrap();
+    patch.apply(&mut session);
+    assert_eq!(session.user, "grace");
+    assert_eq!(session.handle, Handle(7));
+}
@@ -0,0 +1,12 @@
+use jsonable::*;
+
+struct Handle(u32);
+
+#[derive(Jsonable)]
+struct Session {
+    pub user: String,
+    #[jsonable(skip)]
+    pub handle: Handle
+}
+
+fn main() {}
@@ -0,0 +1,72 @@
+use jsonable::*;
+use serde_json::json;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod hex_u64 {
+    use jsonable::{JsonableError, Result};
+    use serde_json::Value;
+
+    pub fn to_json(value: &u64) -> Value {
+        Value::String(format!("{:x}", value))
+    }
+
+    pub fn from_json_unchecked(json: Value) -> u64 {
+        u64::from_str_radix(json.as_str().unwrap(), 16).unwrap()
+    }
+
+    pub fn validate_json(json: &Value) -> Result<()> {
+        match json {
+            Value::String(text) => u64::from_str_radix(text, 16).map(|_| ()).map_err(|_| {
+                JsonableError::InvalidFormat { expected: "hex string", got: text.clone() }
+            }),
+            _ => Err(JsonableError::IncompatibleJsonType { got: "other", expected: "string" }),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Block {
+    #[jsonable(with = "hex_u64")]
+    pub id: u64,
+    pub height: u64,
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Event {
+    #[jsonable(with = "jsonable::rfc3339")]
+    pub at: SystemTime,
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Ids(#[jsonable(with = "self::hex_u64")] u64, u64);
+
+fn main() {
+    let block = Block { id: 255, height: 255 };
+    assert_eq!(block.to_json(), json!({ "id": "ff", "height": 255 }));
+    assert_eq!(block.into_json(), json!({ "id": "ff", "height": 255 }));
+    assert_eq!(Block::from_json(json!({ "id": "ff", "height": 255 })), Ok(Block { id: 255, height: 255 }));
+
+    assert_eq!(
+        Block::validate_json(&json!({ "id": "zz", "height": 1 })),
+        Err(JsonableError::InvalidMapValue {
+            key: "id".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: "u64",
+                error: Box::from(JsonableError::InvalidFormat { expected: "hex string", got: "zz".into() })
+            })
+        })
+    );
+    assert!(Block::validate_json(&json!({ "id": 255, "height": 1 })).is_err());
+
+    assert_eq!(Block::json_schema()["properties"]["id"], json!({}));
+    assert_eq!(Block::json_schema()["required"], json!(["id", "height"]));
+
+    assert_eq!(Ids(16, 16).to_json(), json!(["10", 16]));
+    assert_eq!(Ids::from_json(json!(["10", 16])), Ok(Ids(16, 16)));
+    assert_eq!(Ids::json_schema()["prefixItems"][0], json!({}));
+
+    let event = Event { at: UNIX_EPOCH - Duration::from_secs(1) };
+    assert_eq!(event.to_json(), json!({ "at": "1969-12-31T23:59:59Z" }));
+    assert_eq!(Event::from_json(event.to_json()), Ok(event));
+}
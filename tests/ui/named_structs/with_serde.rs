@@ -0,0 +1,42 @@
+use jsonable::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Coordinates {
+    lat: f64,
+    lon: f64
+}
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Place {
+    pub name: String,
+    #[jsonable(with_serde)]
+    pub location: Coordinates,
+    #[jsonable(with_serde)]
+    pub previous: Option<Coordinates>
+}
+
+fn main() {
+    let place = Place {
+        name: "Harbour".into(),
+        location: Coordinates { lat: 59.9, lon: 10.7 },
+        previous: None
+    };
+    let json = place.to_json();
+    assert_eq!(json, json!({ "name": "Harbour", "location": { "lat": 59.9, "lon": 10.7 }, "previous": null }));
+    assert_eq!(Place::from_json(json.clone()), Ok(place));
+    assert!(Place::from_json_fused(json).is_ok());
+
+    let error = Place::validate_json(&json!({ "name": "Harbour", "location": { "lat": 59.9 } })).unwrap_err();
+    assert_eq!(
+        error,
+        JsonableError::InvalidMapValue {
+            key: "location".into(),
+            error: Box::from(JsonableError::InnerErrorForType {
+                ty: std::any::type_name::<Coordinates>(),
+                error: Box::from(JsonableError::Serde("missing field `lon`".into()))
+            })
+        }
+    );
+}
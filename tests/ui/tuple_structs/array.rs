@@ -0,0 +1,29 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Point(i32, i32, Option<String>);
+
+fn main() {
+    let point = Point(1, -2, Some("origin".into()));
+    assert_eq!(point.to_json(), json!([1, -2, "origin"]));
+    assert_eq!(Point::from_json(point.to_json()), Ok(point));
+    assert_eq!(Point::from_json(json!([3, 4, null])), Ok(Point(3, 4, None)));
+
+    assert_eq!(
+        Point::validate_json(&json!([1, 2])),
+        Err(JsonableError::InvalidArrayLength { got: 2, expected: 3 })
+    );
+    assert_eq!(
+        Point::from_json(json!([1, 2, null, 4])),
+        Err(JsonableError::InvalidArrayLength { got: 4, expected: 3 })
+    );
+    assert_eq!(
+        Point::validate_json(&json!({ "0": 1, "1": 2, "2": null })),
+        Err(JsonableError::IncompatibleJsonType { got: "object", expected: "array" })
+    );
+    assert!(matches!(
+        Point::from_json(json!([1, "two", null])),
+        Err(JsonableError::IncompatibleEntryAt { index: 1, .. })
+    ));
+}
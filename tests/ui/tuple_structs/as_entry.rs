@@ -0,0 +1,28 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(as_entry)]
+struct Entry(String, u32);
+
+fn main() {
+    let entry = Entry("port".into(), 8080);
+    let json = entry.to_json();
+    assert_eq!(json, json!({ "port": 8080 }));
+    assert_eq!(Entry::from_json(json), Ok(entry));
+
+    let entries = Vec::<Entry>::from_json(json!([{ "host": 1 }, { "port": 2 }])).unwrap();
+    assert_eq!(entries, vec![Entry("host".into(), 1), Entry("port".into(), 2)]);
+
+    assert_eq!(
+        Entry::validate_json(&json!({ "host": 1, "port": 2 })),
+        Err(JsonableError::InvalidObjectLength { got: 2, expected: 1 })
+    );
+    assert_eq!(
+        Entry::validate_json(&json!({ "port": "8080" })),
+        Err(JsonableError::InnerErrorForType {
+            ty: "u32",
+            error: Box::from(JsonableError::IncompatibleJsonType { got: "string", expected: "number" })
+        })
+    );
+}
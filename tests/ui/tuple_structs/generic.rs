@@ -0,0 +1,16 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct Pair<T>(T, T);
+
+fn main() {
+    let pair = Pair::<u8>::from_json(json!([1, 2])).unwrap();
+    assert_eq!(pair, Pair(1, 2));
+    assert_eq!(pair.to_json(), json!([1, 2]));
+
+    let pair = Pair(String::from("left"), String::from("right"));
+    assert_eq!(Pair::<String>::from_json(pair.to_json()), Ok(pair));
+
+    assert!(Pair::<u8>::validate_json(&json!([1, "two"])).is_err());
+}
@@ -0,0 +1,32 @@
+use jsonable::*;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(transparent)]
+struct UserId(u64);
+
+#[derive(Debug, PartialEq, Jsonable)]
+#[jsonable(transparent)]
+struct Tags(#[jsonable(dedup)] Vec<String>);
+
+#[derive(Debug, PartialEq, Jsonable)]
+struct User {
+    pub id: UserId,
+    pub friends: Vec<UserId>,
+}
+
+fn main() {
+    assert_eq!(UserId(42).to_json(), json!(42));
+    assert_eq!(UserId(42).into_json(), json!(42));
+    assert_eq!(UserId::from_json(json!(42)), Ok(UserId(42)));
+    assert_eq!(UserId::json_schema(), u64::json_schema());
+
+    assert_eq!(UserId::validate_json(&json!("42")), u64::validate_json(&json!("42")));
+    assert_eq!(UserId::from_json(json!(-1)), u64::from_json(json!(-1)).map(UserId));
+
+    assert_eq!(Tags::from_json(json!(["a", "b", "a"])), Ok(Tags(vec!["a".into(), "b".into()])));
+
+    let user = User::from_json(json!({ "id": 1, "friends": [2, 3] })).unwrap();
+    assert_eq!(user, User { id: UserId(1), friends: vec![UserId(2), UserId(3)] });
+    assert_eq!(user.to_json(), json!({ "id": 1, "friends": [2, 3] }));
+}